@@ -0,0 +1,94 @@
+use std::{fmt::Display, sync::Arc};
+
+#[cfg(feature = "units")]
+use crate::units::ParseQuantityError;
+
+/// Error type of the crate.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// Particle with given name does not have a required parameter.
+    MissingParticleParam {
+        particle: String,
+        param: &'static str,
+    },
+    /// Particle has parameters that can not be used together.
+    ConflictingParticleParams {
+        particle: String,
+        first: &'static str,
+        second: &'static str,
+    },
+    /// Particle composition does not have a required parameter.
+    MissingParam { param: &'static str },
+    /// Particle composition has a wrong number of particles.
+    ParticleCount { expected: usize, found: usize },
+    /// Particle definition file has a malformed entry at given line.
+    ParticleDefinition { line: usize, message: String },
+    /// Isotopologue of the molecule could not be created.
+    Isotopologue { molecule: String, message: String },
+    /// File could not be read.
+    ReadFile { path: String, source: IoError },
+    /// Value with unit could not be parsed.
+    #[cfg(feature = "units")]
+    Parse(ParseQuantityError),
+}
+
+/// Shared I/O error, compared by its kind to keep [`Error`] comparable.
+#[derive(Debug, Clone)]
+pub struct IoError(pub Arc<std::io::Error>);
+
+impl PartialEq for IoError {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.kind() == other.0.kind()
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::MissingParticleParam { particle, param } => {
+                write!(f, "particle \"{particle}\" is missing parameter {param}")
+            }
+            Error::ConflictingParticleParams {
+                particle,
+                first,
+                second,
+            } => write!(
+                f,
+                "particle \"{particle}\" has conflicting parameters {first} and {second}"
+            ),
+            Error::MissingParam { param } => write!(f, "missing parameter {param}"),
+            Error::ParticleCount { expected, found } => {
+                write!(f, "expected {expected} particles, found {found}")
+            }
+            Error::ParticleDefinition { line, message } => write!(f, "line {line}: {message}"),
+            Error::Isotopologue { molecule, message } => {
+                write!(f, "isotopologue of \"{molecule}\": {message}")
+            }
+            Error::ReadFile { path, source } => {
+                write!(f, "could not read \"{path}\": {}", source.0)
+            }
+            #[cfg(feature = "units")]
+            Error::Parse(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "units")]
+            Error::Parse(error) => Some(error),
+            Error::ReadFile { source, .. } => Some(source.0.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "units")]
+impl From<ParseQuantityError> for Error {
+    fn from(error: ParseQuantityError) -> Self {
+        Error::Parse(error)
+    }
+}
@@ -1,3 +1,4 @@
+pub mod error;
 pub mod utility;
 
 #[cfg(feature = "params")]
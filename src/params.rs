@@ -1,43 +1,310 @@
+pub mod isotopes;
+pub mod jacobi;
+pub mod long_range;
+pub mod molecules;
 pub mod particle;
 pub mod particle_factory;
+pub mod particle_file;
 pub mod particles;
+#[cfg(feature = "serde")]
+pub mod registry;
+pub mod thermal;
 
 use std::{
-    any::{Any, TypeId},
+    any::{type_name, Any, TypeId},
     collections::HashMap,
+    fmt::{Debug, Display},
+    hash::Hash,
 };
 
+use crate::units::{energy_units::Energy, mass_units::Mass, uncertain::Uncertain, Au};
+
+use long_range::{Charge, Polarizability, C3, C4, C6, C8};
+use particle_factory::{
+    AtomicNumber, CentrifugalDistortion, Composition, ElectronGFactor, ElectronSpin,
+    HyperfineConst, MassNumber, NaturalAbundance, NuclearGFactor, NuclearMagneticMoment,
+    NuclearSpin, PermanentDipole, QuadrupoleConst, QuantumStatistics, RotConst, SpinRotation,
+    VibConsts,
+};
+
+/// Value that can be stored in [`Params`].
+/// Implemented for all `Clone + Debug + Send + Sync` types,
+/// so that [`Params`] can be cloned, printed and shared between threads.
+pub trait Param: Any + Debug + Send + Sync {
+    fn clone_box(&self) -> Box<dyn Param>;
+
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: Any + Clone + Debug + Send + Sync> Param for T {
+    fn clone_box(&self) -> Box<dyn Param> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Key of a parameter stored in [`Params`] given by its type and optional name.
+#[derive(Debug, Clone)]
+pub struct ParamKey {
+    type_id: TypeId,
+    type_name: &'static str,
+    name: Option<String>,
+}
+
+impl ParamKey {
+    fn of<T: 'static>(name: Option<&str>) -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            type_name: type_name::<T>(),
+            name: name.map(|name| name.to_string()),
+        }
+    }
+
+    /// Name of the parameter if it was inserted with [`Params::insert_named`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Name of the parameter type, intended only for diagnostics.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Readable label of the parameter type, e.g. `"mass"` for `Mass<Au>`
+    /// or `"c6"` for [`long_range::C6`], the same as its registered name.
+    pub fn label(&self) -> &'static str {
+        label(self.type_id, self.type_name)
+    }
+
+    /// Checks whether the parameter is of type `T`.
+    pub fn is<T: 'static>(&self) -> bool {
+        self.type_id == TypeId::of::<T>()
+    }
+}
+
+impl Display for ParamKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{}:{name}", self.label()),
+            None => write!(f, "{}", self.label()),
+        }
+    }
+}
+
+impl PartialEq for ParamKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_id == other.type_id && self.name == other.name
+    }
+}
+
+impl Eq for ParamKey {}
+
+impl Hash for ParamKey {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.type_id.hash(state);
+        self.name.hash(state);
+    }
+}
+
+/// Readable label of parameter type `T` used in error messages, see [`ParamKey::label`].
+pub(crate) fn param_label<T: 'static>() -> &'static str {
+    label(TypeId::of::<T>(), type_name::<T>())
+}
+
+/// Registered types are labelled by their registered names, see [`registry::register`],
+/// other types by their type name without the module path.
+fn label(type_id: TypeId, type_name: &'static str) -> &'static str {
+    #[cfg(feature = "serde")]
+    if let Some(name) = registry::registered_name(type_id) {
+        return name;
+    }
+    if let Some(name) = builtin_name(type_id) {
+        return name;
+    }
+
+    match type_name.contains('<') {
+        true => type_name,
+        false => type_name.rsplit("::").next().unwrap_or(type_name),
+    }
+}
+
+/// Names of the parameter types defined by the crate,
+/// used as their labels and as their keys when serialized.
+pub(crate) fn builtin_names() -> [(TypeId, &'static str); 26] {
+    [
+        (TypeId::of::<Mass<Au>>(), "mass"),
+        (TypeId::of::<Mass<Au, Uncertain>>(), "mass_uncertain"),
+        (TypeId::of::<Energy<Au>>(), "energy"),
+        (TypeId::of::<RotConst>(), "rot_const"),
+        (TypeId::of::<AtomicNumber>(), "atomic_number"),
+        (TypeId::of::<MassNumber>(), "mass_number"),
+        (TypeId::of::<NuclearSpin>(), "nuclear_spin"),
+        (TypeId::of::<NaturalAbundance>(), "natural_abundance"),
+        (
+            TypeId::of::<NuclearMagneticMoment>(),
+            "nuclear_magnetic_moment",
+        ),
+        (TypeId::of::<QuantumStatistics>(), "quantum_statistics"),
+        (
+            TypeId::of::<CentrifugalDistortion>(),
+            "centrifugal_distortion",
+        ),
+        (TypeId::of::<VibConsts>(), "vib_consts"),
+        (TypeId::of::<SpinRotation>(), "spin_rotation"),
+        (TypeId::of::<PermanentDipole>(), "permanent_dipole"),
+        (TypeId::of::<ElectronSpin>(), "electron_spin"),
+        (TypeId::of::<Composition>(), "composition"),
+        (TypeId::of::<HyperfineConst>(), "hyperfine_const"),
+        (TypeId::of::<QuadrupoleConst>(), "quadrupole_const"),
+        (TypeId::of::<ElectronGFactor>(), "electron_g_factor"),
+        (TypeId::of::<NuclearGFactor>(), "nuclear_g_factor"),
+        (TypeId::of::<Charge>(), "charge"),
+        (TypeId::of::<Polarizability>(), "polarizability"),
+        (TypeId::of::<C3>(), "c3"),
+        (TypeId::of::<C4>(), "c4"),
+        (TypeId::of::<C6>(), "c6"),
+        (TypeId::of::<C8>(), "c8"),
+    ]
+}
+
+/// Name of the parameter type defined by the crate, see [`builtin_names`].
+pub(crate) fn builtin_name(type_id: TypeId) -> Option<&'static str> {
+    builtin_names()
+        .into_iter()
+        .find(|(id, _)| *id == type_id)
+        .map(|(_, name)| name)
+}
+
 /// Struct to hold internal parameters.
 /// Used to store information about a particle and composition of particles.
+///
+/// Parameters are keyed by their type, and optionally by a name,
+/// so that several values of the same type can be stored.
+/// # Examples
+/// ```
+/// use quantum::{params::Params, units::{Au, mass_units::Mass}};
+/// let mut params = Params::default();
+/// params
+///     .insert(Mass(1.0, Au))
+///     .insert_named("mass1", Mass(2.0, Au))
+///     .insert_named("mass2", Mass(3.0, Au));
+///
+/// assert_eq!(params.get::<Mass<Au>>().unwrap().value(), 1.0);
+/// assert_eq!(params.get_named::<Mass<Au>>("mass2").unwrap().value(), 3.0);
+/// assert!(params.contains_named::<Mass<Au>>("mass1"));
+/// assert_eq!(params.keys().filter(|key| key.is::<Mass<Au>>()).count(), 3);
+/// ```
 #[derive(Default)]
 pub struct Params {
-    params: HashMap<TypeId, Box<dyn Any>>,
+    params: HashMap<ParamKey, Box<dyn Param>>,
+}
+
+impl Clone for Params {
+    fn clone(&self) -> Self {
+        let params = self
+            .params
+            .iter()
+            .map(|(key, value)| (key.clone(), (**value).clone_box()))
+            .collect();
+
+        Self { params }
+    }
+}
+
+impl Debug for Params {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut map = f.debug_map();
+        for (key, value) in &self.params {
+            map.entry(&format_args!("{key}"), value);
+        }
+
+        map.finish()
+    }
 }
 
 impl Params {
     /// Insert or replace unique parameter of type `T`.
-    pub fn insert<T: 'static>(&mut self, value: T) -> &mut Self {
-        self.params.insert(TypeId::of::<T>(), Box::new(value));
+    pub fn insert<T: Param>(&mut self, value: T) -> &mut Self {
+        self.params.insert(ParamKey::of::<T>(None), Box::new(value));
+
+        self
+    }
+
+    /// Insert or replace parameter of type `T` with given name.
+    pub fn insert_named<T: Param>(&mut self, name: &str, value: T) -> &mut Self {
+        self.params
+            .insert(ParamKey::of::<T>(Some(name)), Box::new(value));
 
         self
     }
 
     /// Removes parameter of type `T`.
     pub fn remove<T: 'static>(&mut self) {
-        self.params.remove(&TypeId::of::<T>());
+        self.params.remove(&ParamKey::of::<T>(None));
     }
 
-    /// Returns the reference of parameter of type `T` with given name if it exists.
+    /// Removes parameter of type `T` with given name.
+    pub fn remove_named<T: 'static>(&mut self, name: &str) {
+        self.params.remove(&ParamKey::of::<T>(Some(name)));
+    }
+
+    /// Returns the reference of parameter of type `T` if it exists.
     pub fn get<T: 'static>(&self) -> Option<&T> {
         self.params
-            .get(&TypeId::of::<T>())
-            .and_then(|value| value.downcast_ref::<T>())
+            .get(&ParamKey::of::<T>(None))
+            .and_then(|value| (**value).as_any().downcast_ref::<T>())
     }
 
-    /// Returns the mutable reference of parameter of type `T` with given name if it exists.
+    /// Returns the mutable reference of parameter of type `T` if it exists.
     pub fn get_mut<T: 'static>(&mut self) -> Option<&mut T> {
         self.params
-            .get_mut(&TypeId::of::<T>())
-            .and_then(|value| value.downcast_mut::<T>())
+            .get_mut(&ParamKey::of::<T>(None))
+            .and_then(|value| (**value).as_any_mut().downcast_mut::<T>())
+    }
+
+    /// Returns the reference of parameter of type `T` with given name if it exists.
+    pub fn get_named<T: 'static>(&self, name: &str) -> Option<&T> {
+        self.params
+            .get(&ParamKey::of::<T>(Some(name)))
+            .and_then(|value| (**value).as_any().downcast_ref::<T>())
+    }
+
+    /// Returns the mutable reference of parameter of type `T` with given name if it exists.
+    pub fn get_named_mut<T: 'static>(&mut self, name: &str) -> Option<&mut T> {
+        self.params
+            .get_mut(&ParamKey::of::<T>(Some(name)))
+            .and_then(|value| (**value).as_any_mut().downcast_mut::<T>())
+    }
+
+    /// Checks whether unique parameter of type `T` exists.
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.params.contains_key(&ParamKey::of::<T>(None))
+    }
+
+    /// Checks whether parameter of type `T` with given name exists.
+    pub fn contains_named<T: 'static>(&self, name: &str) -> bool {
+        self.params.contains_key(&ParamKey::of::<T>(Some(name)))
+    }
+
+    /// Iterates over keys of all stored parameters in arbitrary order.
+    pub fn keys(&self) -> impl Iterator<Item = &ParamKey> {
+        self.params.keys()
+    }
+
+    pub fn len(&self) -> usize {
+        self.params.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
     }
 }
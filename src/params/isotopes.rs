@@ -0,0 +1,179 @@
+use std::sync::OnceLock;
+
+use crate::units::{
+    magnetic_field_units::{MagneticMoment, NuclearMagneton},
+    mass_units::{Dalton, Mass},
+};
+
+const ISOTOPES_TABLE: &str = include_str!("../../data/isotopes.dat");
+const ELEMENTS_TABLE: &str = include_str!("../../data/elements.dat");
+
+/// Nuclear data of a stable or primordial long-lived isotope.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Isotope {
+    pub symbol: &'static str,
+    pub atomic_number: u32,
+    pub mass_number: u32,
+    pub mass: Mass<Dalton>,
+    /// Natural abundance as a fraction
+    pub abundance: f64,
+    /// Doubled nuclear spin 2I
+    pub double_spin: u32,
+    pub magnetic_moment: MagneticMoment<NuclearMagneton>,
+}
+
+impl Isotope {
+    /// Returns whether the neutral atom is a fermion,
+    /// which is the case for an odd number of neutrons.
+    pub fn is_fermion(&self) -> bool {
+        (self.mass_number - self.atomic_number) % 2 == 1
+    }
+}
+
+/// Element with its standard atomic weight.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Element {
+    pub symbol: &'static str,
+    pub atomic_number: u32,
+    pub weight: Mass<Dalton>,
+}
+
+/// Returns all isotopes of the embedded table ordered by atomic and mass number.
+pub fn isotopes() -> &'static [Isotope] {
+    static ISOTOPES: OnceLock<Vec<Isotope>> = OnceLock::new();
+
+    ISOTOPES.get_or_init(|| {
+        table_rows(ISOTOPES_TABLE)
+            .map(|columns| Isotope {
+                atomic_number: columns[0].parse().unwrap(),
+                symbol: columns[1],
+                mass_number: columns[2].parse().unwrap(),
+                mass: Mass(columns[3].parse().unwrap(), Dalton),
+                abundance: columns[4].parse().unwrap(),
+                double_spin: parse_double_spin(columns[5]).unwrap(),
+                magnetic_moment: MagneticMoment(columns[6].parse().unwrap(), NuclearMagneton),
+            })
+            .collect()
+    })
+}
+
+/// Returns all elements of the embedded table ordered by atomic number.
+pub fn elements() -> &'static [Element] {
+    static ELEMENTS: OnceLock<Vec<Element>> = OnceLock::new();
+
+    ELEMENTS.get_or_init(|| {
+        table_rows(ELEMENTS_TABLE)
+            .map(|columns| Element {
+                atomic_number: columns[0].parse().unwrap(),
+                symbol: columns[1],
+                weight: Mass(columns[2].parse().unwrap(), Dalton),
+            })
+            .collect()
+    })
+}
+
+/// Returns the isotope given in one of the notations `"Rb87"`, `"87Rb"` or `"Rb-87"`.
+/// # Examples
+/// ```
+/// use quantum::params::isotopes::isotope;
+/// let rb87 = isotope("Rb87").unwrap();
+/// assert_eq!(rb87.mass_number, 87);
+/// assert_eq!(rb87.double_spin, 3);
+/// assert_eq!(isotope("87Rb"), Some(rb87));
+/// assert_eq!(isotope("Rb-87"), Some(rb87));
+/// assert_eq!(isotope("Rb88"), None);
+/// ```
+pub fn isotope(name: &str) -> Option<&'static Isotope> {
+    let (symbol, mass_number) = split_isotope_name(name)?;
+
+    isotopes()
+        .iter()
+        .find(|isotope| isotope.symbol == symbol && isotope.mass_number == mass_number)
+}
+
+/// Returns the element with given symbol.
+pub fn element(symbol: &str) -> Option<&'static Element> {
+    elements().iter().find(|element| element.symbol == symbol)
+}
+
+/// Returns the natural isotopes of the element with given symbol.
+pub fn isotopes_of(symbol: &str) -> impl Iterator<Item = &'static Isotope> + '_ {
+    isotopes()
+        .iter()
+        .filter(move |isotope| isotope.symbol == symbol)
+}
+
+fn split_isotope_name(name: &str) -> Option<(&str, u32)> {
+    let name = name.trim();
+    let (symbol, number) = if name.starts_with(|c: char| c.is_ascii_digit()) {
+        let split = name.find(|c: char| !c.is_ascii_digit())?;
+        let (number, symbol) = name.split_at(split);
+
+        (symbol, number)
+    } else {
+        let split = name.find(|c: char| !c.is_ascii_alphabetic())?;
+        let (symbol, number) = name.split_at(split);
+
+        (symbol, number.strip_prefix('-').unwrap_or(number))
+    };
+
+    if symbol.is_empty() || !symbol.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    Some((symbol, number.parse().ok()?))
+}
+
+pub(crate) fn table_rows(table: &'static str) -> impl Iterator<Item = Vec<&'static str>> {
+    table
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .skip(1)
+        .map(|line| line.split_whitespace().collect())
+}
+
+/// Parses spin given as `"3/2"` or `"1"` into the doubled spin.
+pub(crate) fn parse_double_spin(spin: &str) -> Option<u32> {
+    match spin.trim().split_once('/') {
+        Some((numerator, "2")) => numerator.trim().parse().ok(),
+        Some(_) => None,
+        None => spin.trim().parse::<u32>().ok().map(|spin| 2 * spin),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{element, isotope, isotopes, isotopes_of};
+
+    #[test]
+    fn isotope_table() {
+        assert!(isotopes().len() > 250);
+
+        for isotope in isotopes() {
+            assert_eq!(isotope.double_spin % 2 == 1, isotope.mass_number % 2 == 1);
+            assert!((isotope.mass.value() - isotope.mass_number as f64).abs() < 0.1);
+        }
+
+        for element in ["H", "Li", "K", "Rb", "Sr", "Cs", "Dy", "Er", "Yb"] {
+            let total: f64 = isotopes_of(element).map(|x| x.abundance).sum();
+            assert!((total - 1.0).abs() < 1e-3, "{element}: {total}");
+        }
+    }
+
+    #[test]
+    fn isotope_lookup() {
+        let k40 = isotope("K-40").unwrap();
+        assert!(k40.is_fermion());
+        assert_eq!(k40.double_spin, 8);
+        assert_eq!(k40.atomic_number, 19);
+
+        assert!(isotope("6Li").unwrap().is_fermion());
+        assert!(!isotope("Li7").unwrap().is_fermion());
+        assert!(isotope("Li").is_none());
+        assert!(isotope("Li-").is_none());
+        assert!(isotope("6-Li").is_none());
+
+        assert_eq!(element("Ne").unwrap().weight.value(), 20.1797);
+        assert!(element("Xx").is_none());
+    }
+}
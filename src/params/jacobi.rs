@@ -0,0 +1,114 @@
+/// Arrangement channel of a three body system labelled by the particle
+/// separated from the pair of the remaining two,
+/// e.g. `Arrangement(0)` is the A + BC channel of the ABC system.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Arrangement(pub usize);
+
+impl Arrangement {
+    /// Returns all three arrangement channels.
+    pub fn all() -> [Arrangement; 3] {
+        [Arrangement(0), Arrangement(1), Arrangement(2)]
+    }
+
+    /// Returns the Jacobi order of the particles, the pair first and the separated particle last.
+    /// # Panics
+    /// Panics if the arrangement index is larger than 2.
+    pub fn order(&self) -> [usize; 3] {
+        assert!(self.0 < 3, "arrangement index {} out of range", self.0);
+
+        [(self.0 + 1) % 3, (self.0 + 2) % 3, self.0]
+    }
+}
+
+/// Sequential Jacobi coordinates of N particles,
+/// the k-th Jacobi vector connects the center of mass of the first k + 1 particles
+/// with the (k + 2)-th particle.
+/// All masses are in atomic units.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Jacobi {
+    red_masses: Vec<f64>,
+    total_mass: f64,
+}
+
+impl Jacobi {
+    /// Creates Jacobi coordinates of the particles with given masses coupled in the given order.
+    /// # Examples
+    /// ```
+    /// use quantum::params::jacobi::Jacobi;
+    /// let jacobi = Jacobi::new(&[1.0, 2.0, 3.0]);
+    /// assert_eq!(jacobi.red_masses(), [2.0 / 3.0, 1.5]);
+    /// assert_eq!(jacobi.total_mass(), 6.0);
+    /// assert!((jacobi.hyperspherical_red_mass().unwrap() - 1.0).abs() < 1e-15);
+    /// ```
+    pub fn new(masses: &[f64]) -> Self {
+        let mut total_mass = masses.first().copied().unwrap_or_default();
+        let mut red_masses = Vec::with_capacity(masses.len().saturating_sub(1));
+
+        for &mass in masses.iter().skip(1) {
+            red_masses.push(total_mass * mass / (total_mass + mass));
+            total_mass += mass;
+        }
+
+        Self {
+            red_masses,
+            total_mass,
+        }
+    }
+
+    /// Returns the reduced masses of the consecutive Jacobi vectors.
+    pub fn red_masses(&self) -> &[f64] {
+        &self.red_masses
+    }
+
+    pub fn total_mass(&self) -> f64 {
+        self.total_mass
+    }
+
+    /// Returns the mass-scaled hyperspherical reduced mass `(μ₁μ₂…μₙ₋₁)^(1 / (N - 1))`,
+    /// which does not depend on the order of the particles and
+    /// equals the reduced mass for two particles.
+    /// Returns `None` for fewer than two particles, which have no Jacobi vectors.
+    pub fn hyperspherical_red_mass(&self) -> Option<f64> {
+        if self.red_masses.is_empty() {
+            return None;
+        }
+        let n = self.red_masses.len() as f64;
+
+        Some(self.red_masses.iter().product::<f64>().powf(1.0 / n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Arrangement, Jacobi};
+
+    #[test]
+    fn triatomic_channels() {
+        let masses = [7.0, 87.0, 40.0];
+        let total = masses.iter().sum::<f64>();
+        let hyper_mass = (masses.iter().product::<f64>() / total).sqrt();
+
+        for arrangement in Arrangement::all() {
+            let [b, c, a] = arrangement.order();
+            let jacobi = Jacobi::new(&[masses[b], masses[c], masses[a]]);
+
+            let pair = masses[b] * masses[c] / (masses[b] + masses[c]);
+            let atom_pair = masses[a] * (masses[b] + masses[c]) / total;
+            assert!((jacobi.red_masses()[0] / pair - 1.0).abs() < 1e-14);
+            assert!((jacobi.red_masses()[1] / atom_pair - 1.0).abs() < 1e-14);
+            assert_eq!(jacobi.total_mass(), total);
+            let red_mass = jacobi.hyperspherical_red_mass().unwrap();
+            assert!((red_mass / hyper_mass - 1.0).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn single_particle() {
+        assert_eq!(Jacobi::new(&[]).hyperspherical_red_mass(), None);
+
+        let jacobi = Jacobi::new(&[7.0]);
+        assert!(jacobi.red_masses().is_empty());
+        assert_eq!(jacobi.total_mass(), 7.0);
+        assert_eq!(jacobi.hyperspherical_red_mass(), None);
+    }
+}
@@ -0,0 +1,190 @@
+use std::sync::OnceLock;
+
+use super::{
+    isotopes::table_rows,
+    particle::Particle,
+    particle_factory::{AtomicNumber, PermanentDipole},
+    Params,
+};
+
+const LONG_RANGE_TABLE: &str = include_str!("../../data/long_range.dat");
+
+/// Charge in units of the elementary charge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Charge(pub i32);
+
+/// Static dipole polarizability in atomic units.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polarizability(pub f64);
+
+/// Coefficient of the -C₃/R³ long-range potential in atomic units.
+/// Computed for a pair of polar particles it is the strength `dᴬdᴮ`
+/// of the dipole-dipole interaction `C₃ (1 - 3cos²θ) / R³`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct C3(pub f64);
+
+/// Coefficient of the -C₄/R⁴ long-range potential in atomic units.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct C4(pub f64);
+
+/// Coefficient of the -C₆/R⁶ long-range potential in atomic units.
+/// Inserted into a [`Particle`] it is the coefficient of the homonuclear pair.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct C6(pub f64);
+
+/// Coefficient of the -C₈/R⁸ long-range potential in atomic units.
+/// Inserted into a [`Particle`] it is the coefficient of the homonuclear pair.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct C8(pub f64);
+
+struct AtomLongRange {
+    symbol: &'static str,
+    polarizability: f64,
+    c6: f64,
+    c8: Option<f64>,
+}
+
+fn atoms_long_range() -> &'static [AtomLongRange] {
+    static ATOMS: OnceLock<Vec<AtomLongRange>> = OnceLock::new();
+
+    ATOMS.get_or_init(|| {
+        table_rows(LONG_RANGE_TABLE)
+            .map(|columns| AtomLongRange {
+                symbol: columns[0],
+                polarizability: columns[1].parse().unwrap(),
+                c6: columns[2].parse().unwrap(),
+                c8: columns[3].parse().ok(),
+            })
+            .collect()
+    })
+}
+
+/// Inserts the tabulated ground state [`Polarizability`], homonuclear [`C6`] and [`C8`]
+/// of the element into the particle parameters.
+pub(crate) fn insert_atom_long_range(params: &mut Params, symbol: &str) {
+    let Some(atom) = atoms_long_range().iter().find(|x| x.symbol == symbol) else {
+        return;
+    };
+
+    params
+        .insert(Polarizability(atom.polarizability))
+        .insert(C6(atom.c6));
+    if let Some(c8) = atom.c8 {
+        params.insert(C8(c8));
+    }
+}
+
+/// Computes the long-range coefficients of the pair and inserts them into `params`:
+/// - [`C4`] of the charge-induced dipole interaction `q²α / 2` if exactly one particle is charged,
+///   the dispersion coefficients are not computed for charged particles,
+/// - [`C3`] of neutral particles with nonzero [`PermanentDipole`]s as the product of the dipoles,
+/// - [`C6`] of neutral particles from the homonuclear coefficients and polarizabilities
+///   by the combination rule `2 C₆ᴬ C₆ᴮ / (αᴮ/αᴬ C₆ᴬ + αᴬ/αᴮ C₆ᴮ)`,
+/// - [`C8`] of homonuclear pairs, identified by the same [`AtomicNumber`].
+///
+/// Coefficients that can not be computed from the available particle parameters are not inserted.
+pub(crate) fn insert_pair_long_range(params: &mut Params, first: &Particle, second: &Particle) {
+    let charge = |particle: &Particle| particle.get::<Charge>().map_or(0, |charge| charge.0);
+    let polarizability = |particle: &Particle| particle.get::<Polarizability>().map(|x| x.0);
+
+    match (charge(first), charge(second)) {
+        (0, 0) => {}
+        (q, 0) | (0, q) => {
+            let neutral = if charge(first) == 0 { first } else { second };
+            if let Some(alpha) = polarizability(neutral) {
+                params.insert(C4((q * q) as f64 * alpha / 2.0));
+            }
+
+            return;
+        }
+        _ => return,
+    }
+
+    let dipole = |particle: &Particle| particle.get::<PermanentDipole>().map(|x| x.0.value());
+    if let (Some(d_a), Some(d_b)) = (dipole(first), dipole(second)) {
+        if d_a != 0.0 && d_b != 0.0 {
+            params.insert(C3(d_a * d_b));
+        }
+    }
+
+    let c6 = |particle: &Particle| particle.get::<C6>().map(|x| x.0);
+    if let (Some(c6_a), Some(c6_b), Some(alpha_a), Some(alpha_b)) = (
+        c6(first),
+        c6(second),
+        polarizability(first),
+        polarizability(second),
+    ) {
+        let c6 = 2.0 * c6_a * c6_b / (alpha_b / alpha_a * c6_a + alpha_a / alpha_b * c6_b);
+        params.insert(C6(c6));
+    }
+
+    let homonuclear = match (first.get::<AtomicNumber>(), second.get::<AtomicNumber>()) {
+        (Some(first), Some(second)) => first == second,
+        _ => false,
+    };
+    if homonuclear {
+        if let Some(c8) = first.get::<C8>() {
+            params.insert(*c8);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        params::particle_factory::{create_atom, create_molecule},
+        units::{
+            electric_field_units::{Debye, ElectricDipole},
+            Au,
+        },
+    };
+
+    use super::{insert_pair_long_range, Charge, C3, C4, C6, C8};
+
+    #[test]
+    fn combination_rule() {
+        let pair = |first: &str, second: &str| {
+            let mut params = crate::params::Params::default();
+            let first = create_atom(first).unwrap();
+            let second = create_atom(second).unwrap();
+            insert_pair_long_range(&mut params, &first, &second);
+
+            params
+        };
+
+        let rb_rb = pair("Rb87", "Rb85");
+        assert_eq!(rb_rb.get::<C6>(), Some(&C6(4698.0)));
+        assert_eq!(rb_rb.get::<C8>(), Some(&C8(577000.0)));
+        assert!(rb_rb.get::<C4>().is_none());
+
+        let k_rb = pair("K40", "Rb87").get::<C6>().unwrap().0;
+        assert!((k_rb - 4274.0).abs() < 30.0, "{k_rb}");
+        assert!(pair("K40", "Rb87").get::<C8>().is_none());
+
+        let mut params = crate::params::Params::default();
+        let mut ion = create_atom("Yb174").unwrap();
+        ion.insert(Charge(1));
+        insert_pair_long_range(&mut params, &ion, &create_atom("Li6").unwrap());
+        assert_eq!(params.get::<C4>(), Some(&C4(164.1 / 2.0)));
+        assert!(params.get::<C6>().is_none());
+        assert!(params.get::<C3>().is_none());
+
+        let mut params = crate::params::Params::default();
+        let krb = create_molecule("KRb").unwrap();
+        insert_pair_long_range(&mut params, &krb, &create_molecule("NaK").unwrap());
+        let expected = ElectricDipole(0.574, Debye).to(Au).value()
+            * ElectricDipole(2.72, Debye).to(Au).value();
+        let c3 = params.get::<C3>().unwrap().0;
+        assert!((c3 / expected - 1.0).abs() < 1e-12);
+
+        let mut params = crate::params::Params::default();
+        insert_pair_long_range(&mut params, &krb, &create_molecule("N2").unwrap());
+        assert!(params.get::<C3>().is_none());
+    }
+}
@@ -0,0 +1,103 @@
+use std::sync::OnceLock;
+
+use crate::units::{
+    electric_field_units::{Debye, ElectricDipole},
+    energy_units::{CmInv, Energy},
+    mass_units::{Dalton, Mass},
+};
+
+use super::isotopes::{isotope, parse_double_spin, table_rows};
+
+const MOLECULES_TABLE: &str = include_str!("../../data/molecules.dat");
+
+/// Ground state spectroscopic constants of a molecule.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Molecule {
+    pub name: &'static str,
+    /// Constituent isotopes, e.g. `["40K", "87Rb"]`
+    pub composition: Vec<&'static str>,
+    pub rot_const: Energy<CmInv>,
+    pub centrifugal_distortion: Energy<CmInv>,
+    /// Harmonic frequency ωₑ and anharmonicity ωₑxₑ, only for diatomic molecules
+    pub vib_consts: Option<(Energy<CmInv>, Energy<CmInv>)>,
+    pub dipole: ElectricDipole<Debye>,
+    /// Doubled electron spin 2S
+    pub double_spin: u32,
+    pub spin_rotation: Energy<CmInv>,
+}
+
+impl Molecule {
+    /// Returns the mass as the sum of the masses of the constituent atoms.
+    pub fn mass(&self) -> Mass<Dalton> {
+        self.composition
+            .iter()
+            .map(|name| isotope(name).unwrap().mass)
+            .sum()
+    }
+}
+
+/// Returns all molecules of the embedded table.
+pub fn molecules() -> &'static [Molecule] {
+    static MOLECULES: OnceLock<Vec<Molecule>> = OnceLock::new();
+
+    MOLECULES.get_or_init(|| {
+        table_rows(MOLECULES_TABLE)
+            .map(|columns| {
+                let energy = |column: &str| Energy(column.parse().unwrap(), CmInv);
+                let vib_consts = match (columns[4], columns[5]) {
+                    ("-", "-") => None,
+                    (omega_e, omega_e_x_e) => Some((energy(omega_e), energy(omega_e_x_e))),
+                };
+
+                Molecule {
+                    name: columns[0],
+                    composition: columns[1].split(',').collect(),
+                    rot_const: energy(columns[2]),
+                    centrifugal_distortion: energy(columns[3]),
+                    vib_consts,
+                    dipole: ElectricDipole(columns[6].parse().unwrap(), Debye),
+                    double_spin: parse_double_spin(columns[7]).unwrap(),
+                    spin_rotation: energy(columns[8]),
+                }
+            })
+            .collect()
+    })
+}
+
+/// Returns the molecule with given name.
+/// # Examples
+/// ```
+/// use quantum::params::molecules::molecule;
+/// let krb = molecule("KRb").unwrap();
+/// assert_eq!(krb.composition, ["40K", "87Rb"]);
+/// assert!((krb.mass().value() - 126.8732).abs() < 1e-4);
+/// ```
+pub fn molecule(name: &str) -> Option<&'static Molecule> {
+    molecules().iter().find(|molecule| molecule.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{molecule, molecules};
+    use crate::params::isotopes::isotope;
+
+    #[test]
+    fn molecule_table() {
+        for molecule in molecules() {
+            assert!(
+                molecule.composition.iter().all(|x| isotope(x).is_some()),
+                "{}",
+                molecule.name
+            );
+            assert_eq!(
+                molecule.vib_consts.is_none(),
+                molecule.composition.len() > 2
+            );
+        }
+
+        let ocs = molecule("OCS").unwrap();
+        assert!((ocs.mass().value() - 59.9670).abs() < 1e-4);
+        assert_eq!(molecule("CaF").unwrap().double_spin, 1);
+        assert!(molecule("XY").is_none());
+    }
+}
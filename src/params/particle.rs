@@ -1,13 +1,19 @@
 use std::ops::{Deref, DerefMut};
 
 use crate::{
-    params::Params,
-    units::{mass_units::Mass, Au, Unit},
+    error::{Error, Result},
+    params::long_range::{Charge, Polarizability},
+    params::particle_factory::{
+        ElectronGFactor, ElectronSpin, HyperfineConst, NuclearGFactor, NuclearSpin, QuadrupoleConst,
+    },
+    params::{param_label, Params},
+    units::{energy_units::Energy, mass_units::Mass, uncertain::Uncertain, Au, DynUnit},
 };
 
 /// Struct to hold information about a particle.
 /// To create a predefined particle use [`crate::particle_factory`].
-#[derive(Default)]
+#[derive(Default, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Particle {
     name: String,
     pub params: Params,
@@ -15,7 +21,7 @@ pub struct Particle {
 
 impl Particle {
     /// Creates new particle with given name and mass
-    pub fn new<U: Unit>(name: &str, mass: Mass<U>) -> Self {
+    pub fn new<U: DynUnit>(name: &str, mass: Mass<U>) -> Self {
         let mut params = Params::default();
         params.insert(mass.to(Au));
 
@@ -25,9 +31,114 @@ impl Particle {
         }
     }
 
+    /// Creates new particle with given name and mass with uncertainty.
+    /// Only the mass with uncertainty `Mass<Au, Uncertain>` is inserted, see [`Particle::mass`].
+    pub fn new_uncertain<U: DynUnit>(name: &str, mass: Mass<U, Uncertain>) -> Self {
+        let mut params = Params::default();
+        params.insert(mass.to(Au));
+
+        Particle {
+            name: name.to_string(),
+            params,
+        }
+    }
+
+    /// Returns the mass of the particle given either by `Mass<Au>`
+    /// or by the mass with uncertainty `Mass<Au, Uncertain>`.
+    /// Returns error if the particle has none or both of them,
+    /// so replacing the mass requires removing the other variant first.
+    pub fn mass(&self) -> Result<Mass<Au>> {
+        match (
+            self.params.get::<Mass<Au>>(),
+            self.params.get::<Mass<Au, Uncertain>>(),
+        ) {
+            (Some(_), Some(_)) => Err(Error::ConflictingParticleParams {
+                particle: self.name.clone(),
+                first: param_label::<Mass<Au>>(),
+                second: param_label::<Mass<Au, Uncertain>>(),
+            }),
+            (None, Some(mass)) => Ok(Mass(mass.value().value(), Au)),
+            _ => self.try_get::<Mass<Au>>().copied(),
+        }
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    pub(crate) fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+
+    /// Sets the doubled electron spin 2S.
+    /// # Examples
+    /// ```
+    /// use quantum::params::{particle::Particle, particle_factory::{ElectronSpin, HyperfineConst}};
+    /// use quantum::units::{energy_units::{Energy, MHz}, mass_units::{Dalton, Mass}};
+    /// let atom = Particle::new("Rb87", Mass(86.909, Dalton))
+    ///     .with_electron_spin(1)
+    ///     .with_nuclear_spin(3)
+    ///     .with_hyperfine_const(Energy(3417.34, MHz));
+    /// assert_eq!(atom.get::<ElectronSpin>(), Some(&ElectronSpin(1)));
+    /// assert_eq!(atom.get::<HyperfineConst>(), Some(&HyperfineConst(Energy(3417.34, MHz).to_au())));
+    /// ```
+    pub fn with_electron_spin(mut self, double_spin: u32) -> Self {
+        self.params.insert(ElectronSpin(double_spin));
+        self
+    }
+
+    /// Sets the doubled nuclear spin 2I.
+    pub fn with_nuclear_spin(mut self, double_spin: u32) -> Self {
+        self.params.insert(NuclearSpin(double_spin));
+        self
+    }
+
+    /// Sets the magnetic dipole hyperfine constant A_hf.
+    pub fn with_hyperfine_const<U: DynUnit>(mut self, a_hf: Energy<U>) -> Self {
+        self.params.insert(HyperfineConst(a_hf.to_au()));
+        self
+    }
+
+    /// Sets the electric quadrupole hyperfine constant B_hf.
+    pub fn with_quadrupole_const<U: DynUnit>(mut self, b_hf: Energy<U>) -> Self {
+        self.params.insert(QuadrupoleConst(b_hf.to_au()));
+        self
+    }
+
+    /// Sets the electron g-factor, see [`ElectronGFactor`].
+    pub fn with_electron_g_factor(mut self, g_factor: f64) -> Self {
+        self.params.insert(ElectronGFactor(g_factor));
+        self
+    }
+
+    /// Sets the charge in units of the elementary charge.
+    pub fn with_charge(mut self, charge: i32) -> Self {
+        self.params.insert(Charge(charge));
+        self
+    }
+
+    /// Sets the static dipole polarizability in atomic units.
+    pub fn with_polarizability(mut self, polarizability: f64) -> Self {
+        self.params.insert(Polarizability(polarizability));
+        self
+    }
+
+    /// Sets the nuclear g-factor, see [`NuclearGFactor`].
+    pub fn with_nuclear_g_factor(mut self, g_factor: f64) -> Self {
+        self.params.insert(NuclearGFactor(g_factor));
+        self
+    }
+
+    /// Returns the reference of parameter of type `T`
+    /// or error naming the particle and the missing parameter.
+    pub fn try_get<T: 'static>(&self) -> Result<&T> {
+        self.params
+            .get::<T>()
+            .ok_or_else(|| Error::MissingParticleParam {
+                particle: self.name.clone(),
+                param: param_label::<T>(),
+            })
+    }
 }
 
 impl Deref for Particle {
@@ -50,10 +161,14 @@ mod tests {
         params::particle_factory,
         units::{
             mass_units::{Dalton, Mass},
+            uncertain::Uncertain,
             Au,
         },
     };
 
+    use super::Particle;
+
+    #[derive(Clone, Debug)]
     struct Parameter(u32);
 
     #[test]
@@ -74,4 +189,26 @@ mod tests {
         let particle = particle_factory::create_atom("Non existing atom");
         assert!(particle.is_none());
     }
+
+    #[test]
+    fn uncertain_mass() {
+        let mut particle =
+            Particle::new_uncertain("Rb87", Mass(Uncertain::new(86.909, 1e-3), Dalton));
+        assert!(particle.get::<Mass<Au>>().is_none());
+        assert_eq!(particle.mass().unwrap(), Mass(86.909, Dalton));
+
+        particle.insert(Mass(Uncertain::new(86.0, 1e-3), Dalton).to(Au));
+        assert_eq!(particle.mass().unwrap(), Mass(86.0, Dalton));
+
+        particle.insert(Mass(87.0, Dalton).to(Au));
+        assert_eq!(
+            particle.mass().unwrap_err().to_string(),
+            "particle \"Rb87\" has conflicting parameters mass and mass_uncertain"
+        );
+        particle.remove::<Mass<Au, Uncertain>>();
+        assert_eq!(particle.mass().unwrap(), Mass(87.0, Dalton));
+
+        particle.params = Default::default();
+        assert!(particle.mass().is_err());
+    }
 }
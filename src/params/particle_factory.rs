@@ -1,38 +1,537 @@
-use crate::units::mass_units::{Dalton, Mass};
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::{OnceLock, RwLock},
+};
 
-use super::particle::Particle;
+use crate::{
+    error::{Error, Result},
+    units::{
+        electric_field_units::ElectricDipole,
+        energy_units::{Energy, MHz},
+        magnetic_field_units::MagneticMoment,
+        mass_units::{Dalton, Mass},
+        uncertain::Uncertain,
+        Au,
+    },
+};
 
+use super::{isotopes, long_range, molecules, particle::Particle, particle_file};
+
+/// Rotational constant in atomic units.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RotConst(pub f64);
 
+/// Centrifugal distortion constant in atomic units.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CentrifugalDistortion(pub f64);
+
+/// Vibrational constants ωₑ and ωₑxₑ in atomic units.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VibConsts {
+    pub omega_e: f64,
+    pub omega_e_x_e: f64,
+}
+
+/// Spin-rotation constant γ in atomic units.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpinRotation(pub f64);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PermanentDipole(pub ElectricDipole<Au>);
+
+/// Doubled electron spin 2S.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElectronSpin(pub u32);
+
+impl ElectronSpin {
+    /// Returns the electron spin S.
+    pub fn value(&self) -> f64 {
+        self.0 as f64 / 2.0
+    }
+}
+
+/// Isotopes the molecule is composed of, e.g. `["40K", "87Rb"]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Composition(pub Vec<String>);
+
+/// Number of protons in the nucleus.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AtomicNumber(pub u32);
+
+/// Number of nucleons in the nucleus.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MassNumber(pub u32);
+
+/// Doubled nuclear spin 2I.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NuclearSpin(pub u32);
+
+impl NuclearSpin {
+    /// Returns the nuclear spin I.
+    pub fn value(&self) -> f64 {
+        self.0 as f64 / 2.0
+    }
+}
+
+/// Natural abundance of the isotope as a fraction.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NaturalAbundance(pub f64);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NuclearMagneticMoment(pub MagneticMoment<Au>);
+
+/// Magnetic dipole hyperfine constant A_hf in atomic units.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HyperfineConst(pub f64);
+
+/// Electric quadrupole hyperfine constant B_hf in atomic units.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuadrupoleConst(pub f64);
+
+/// Electron g-factor, negative for the free electron as [`CODATA.electron_g_factor`](crate::units::constants::Constants::electron_g_factor),
+/// so that μ = g μ_B S as for the [`NuclearGFactor`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElectronGFactor(pub f64);
+
+/// Nuclear g-factor g = μ / I in units of nuclear magneton, so that μ = g μ_N I.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NuclearGFactor(pub f64);
+
+/// Quantum statistics of the whole particle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum QuantumStatistics {
+    Boson,
+    Fermion,
+}
+
+/// Ground state electron g-factors g_J of hydrogen and alkali atoms, see [`ElectronGFactor`].
+const ALKALI_G_FACTORS: [(&str, f64); 6] = [
+    ("H", -2.0022838),
+    ("Li", -2.0023010),
+    ("Na", -2.0022960),
+    ("K", -2.00229421),
+    ("Rb", -2.00233113),
+    ("Cs", -2.00254032),
+];
+
+/// Ground state magnetic dipole hyperfine constants A_hf of hydrogen and alkali atoms in MHz,
+/// related to the hyperfine splitting by ΔE = A_hf (I + 1/2).
+const ALKALI_HYPERFINE_CONSTS: [(&str, u32, f64); 11] = [
+    ("H", 1, 1420.405751768),
+    ("H", 2, 218.256201),
+    ("Li", 6, 152.1368407),
+    ("Li", 7, 401.7520433),
+    ("Na", 23, 885.8130644),
+    ("K", 39, 230.8598601),
+    ("K", 40, -285.7308),
+    ("K", 41, 127.0069352),
+    ("Rb", 85, 1011.910813),
+    ("Rb", 87, 3417.341305452),
+    ("Cs", 133, 2298.1579425),
+];
+
+fn user_particles() -> &'static RwLock<HashMap<String, Particle>> {
+    static USER_PARTICLES: OnceLock<RwLock<HashMap<String, Particle>>> = OnceLock::new();
+
+    USER_PARTICLES.get_or_init(Default::default)
+}
+
+fn user_particle(name: &str) -> Option<Particle> {
+    user_particles().read().unwrap().get(name).cloned()
+}
+
+/// Adds user defined particle that is returned by [`create_atom`] and [`create_molecule`]
+/// before consulting the built-in tables.
+/// Replaces the previously added particle with the same name.
+pub fn add_user_particle(particle: Particle) {
+    user_particles()
+        .write()
+        .unwrap()
+        .insert(particle.name().to_string(), particle);
+}
+
+/// Loads user defined particles from the file, see [`particle_file::parse_particles`] for the format,
+/// and adds them as in [`add_user_particle`].
+/// Nothing is added if any of the definitions is malformed.
+pub fn load_user_particles(path: impl AsRef<Path>) -> Result<()> {
+    for particle in particle_file::read_particles(path)? {
+        add_user_particle(particle);
+    }
+
+    Ok(())
+}
+
+/// Creates an atom from the isotope database given as `"Rb87"`, `"87Rb"` or `"Rb-87"`,
+/// with the mass, [`AtomicNumber`], [`MassNumber`], [`NuclearSpin`], [`NaturalAbundance`],
+/// [`NuclearMagneticMoment`] and [`QuantumStatistics`] parameters,
+/// and [`NuclearGFactor`] for nonzero nuclear spin.
+/// Atoms with tabulated long-range data get [`Polarizability`](long_range::Polarizability),
+/// homonuclear [`C6`](long_range::C6) and [`C8`](long_range::C8).
+/// Hydrogen and alkali atoms additionally get ground state [`ElectronSpin`],
+/// [`ElectronGFactor`] and [`HyperfineConst`].
+/// An element symbol alone, e.g. `"Ne"`, creates an atom with the standard atomic weight
+/// and only the [`AtomicNumber`] and long-range parameters.
+/// # Examples
+/// ```
+/// use quantum::params::particle_factory::{create_atom, NuclearSpin, QuantumStatistics};
+/// let k40 = create_atom("40K").unwrap();
+/// assert_eq!(k40.get::<NuclearSpin>(), Some(&NuclearSpin(8)));
+/// assert_eq!(k40.get::<QuantumStatistics>(), Some(&QuantumStatistics::Fermion));
+/// ```
 pub fn create_atom(name: &str) -> Option<Particle> {
-    let mass = match name {
-        "Ne" => Mass(20.1797, Dalton),
-        "Li6" => Mass(6.015122, Dalton),
-        "Li7" => Mass(7.016004, Dalton),
-        "Na23" => Mass(22.989770, Dalton),
-        "K40" => Mass(39.963707, Dalton),
-        "Rb85" => Mass(84.911789, Dalton),
-        "Rb87" => Mass(86.90918053, Dalton),
-        "Cs133" => Mass(132.905447, Dalton),
-        _ => return None,
+    if let Some(particle) = user_particle(name) {
+        return Some(particle);
+    }
+
+    if let Some(element) = isotopes::element(name) {
+        let mut particle = Particle::new(name, element.weight);
+        particle.insert(AtomicNumber(element.atomic_number));
+        long_range::insert_atom_long_range(&mut particle, element.symbol);
+
+        return Some(particle);
+    }
+
+    let isotope = isotopes::isotope(name)?;
+    let statistics = if isotope.is_fermion() {
+        QuantumStatistics::Fermion
+    } else {
+        QuantumStatistics::Boson
     };
 
-    Some(Particle::new(name, mass))
+    let mut particle = Particle::new(name, isotope.mass);
+    particle
+        .insert(AtomicNumber(isotope.atomic_number))
+        .insert(MassNumber(isotope.mass_number))
+        .insert(NuclearSpin(isotope.double_spin))
+        .insert(NaturalAbundance(isotope.abundance))
+        .insert(NuclearMagneticMoment(isotope.magnetic_moment.to(Au)))
+        .insert(statistics);
+    long_range::insert_atom_long_range(&mut particle, isotope.symbol);
+
+    if isotope.double_spin > 0 {
+        let g_factor = isotope.magnetic_moment.value() * 2.0 / isotope.double_spin as f64;
+        particle.insert(NuclearGFactor(g_factor));
+    }
+
+    let g_factor = ALKALI_G_FACTORS
+        .iter()
+        .find(|(symbol, _)| *symbol == isotope.symbol);
+    if let Some(&(_, g_factor)) = g_factor {
+        particle
+            .insert(ElectronSpin(1))
+            .insert(ElectronGFactor(g_factor));
+    }
+
+    let hyperfine = ALKALI_HYPERFINE_CONSTS
+        .iter()
+        .find(|(symbol, a, _)| *symbol == isotope.symbol && *a == isotope.mass_number);
+    if let Some(&(_, _, a_hf)) = hyperfine {
+        particle.insert(HyperfineConst(Energy(a_hf, MHz).to_au()));
+    }
+
+    Some(particle)
 }
 
+/// Creates a molecule from the molecule database with the mass computed from its [`Composition`]
+/// and the [`RotConst`], [`CentrifugalDistortion`], [`VibConsts`] (diatomic molecules only),
+/// [`PermanentDipole`], [`ElectronSpin`] and [`SpinRotation`] (open shell molecules only) parameters.
+/// # Examples
+/// ```
+/// use quantum::params::particle_factory::{create_molecule, ElectronSpin, SpinRotation};
+/// let caf = create_molecule("CaF").unwrap();
+/// assert_eq!(caf.get::<ElectronSpin>(), Some(&ElectronSpin(1)));
+/// assert!(caf.get::<SpinRotation>().is_some());
+/// ```
 pub fn create_molecule(name: &str) -> Option<Particle> {
-    let mass = match name {
-        "OCS" => Mass(60.07, Dalton),
-        _ => return None,
+    if let Some(particle) = user_particle(name) {
+        return Some(particle);
+    }
+
+    let molecule = molecules::molecule(name)?;
+
+    let mut particle = Particle::new(name, molecule.mass());
+    particle
+        .insert(Composition(
+            molecule.composition.iter().map(|x| x.to_string()).collect(),
+        ))
+        .insert(RotConst(molecule.rot_const.to_au()))
+        .insert(CentrifugalDistortion(
+            molecule.centrifugal_distortion.to_au(),
+        ))
+        .insert(PermanentDipole(molecule.dipole.to(Au)))
+        .insert(ElectronSpin(molecule.double_spin));
+
+    if let Some((omega_e, omega_e_x_e)) = molecule.vib_consts {
+        particle.insert(VibConsts {
+            omega_e: omega_e.to_au(),
+            omega_e_x_e: omega_e_x_e.to_au(),
+        });
+    }
+    if molecule.double_spin > 0 {
+        particle.insert(SpinRotation(molecule.spin_rotation.to_au()));
+    }
+
+    Some(particle)
+}
+
+/// Creates an isotopologue of the diatomic molecule with the constituent isotopes
+/// replaced by the given `composition`, keeping the parent parameters.
+/// The constants are rescaled with the reduced mass ratio `ρ = (μ / μ')^(1/2)` as
+/// `B' = ρ²B`, `D' = ρ⁴D`, `γ' = ρ²γ`, `ωₑ' = ρωₑ` and `ωₑxₑ' = ρ²ωₑxₑ`.
+/// The name of the isotopologue is the composition in the mass number first notation,
+/// e.g. `"39K87Rb"` for both `["39K", "87Rb"]` and `["K-39", "Rb87"]`.
+///
+/// Returns error if the parent is missing [`Composition`],
+/// is not diatomic or the composition is not an isotope substitution of the parent.
+/// # Examples
+/// ```
+/// use quantum::params::particle_factory::{create_molecule, isotopologue, RotConst};
+/// let krb = create_molecule("KRb").unwrap();
+/// let krb_39 = isotopologue(&krb, &["K-39", "87Rb"]).unwrap();
+/// assert_eq!(krb_39.name(), "39K87Rb");
+///
+/// let ratio = krb_39.get::<RotConst>().unwrap().0 / krb.get::<RotConst>().unwrap().0;
+/// assert!((ratio - 1.0176).abs() < 1e-4);
+/// ```
+pub fn isotopologue(parent: &Particle, composition: &[&str]) -> Result<Particle> {
+    let error = |message: String| Error::Isotopologue {
+        molecule: parent.name().to_string(),
+        message,
     };
 
-    let rot_const = match name {
-        "OCS" => 9.243165268327e-7,
-        _ => return None,
+    let parent_composition = &parent.try_get::<Composition>()?.0;
+    if parent_composition.len() != 2 {
+        return Err(error(
+            "only diatomic molecules can be rescaled by the reduced mass".to_string(),
+        ));
+    }
+    if composition.len() != 2 {
+        return Err(error(format!(
+            "expected 2 isotopes, got {}",
+            composition.len()
+        )));
+    }
+
+    let isotope = |name: &str| {
+        isotopes::isotope(name).ok_or_else(|| error(format!("unknown isotope \"{name}\"")))
     };
+    let mut masses = [[0.0; 2]; 2];
+    let mut names = Vec::with_capacity(2);
+    for (i, (parent_name, name)) in parent_composition.iter().zip(composition).enumerate() {
+        let parent_isotope = isotope(parent_name)?;
+        let isotope = isotope(name)?;
+        if parent_isotope.atomic_number != isotope.atomic_number {
+            return Err(error(format!(
+                "\"{name}\" is not an isotope of {}",
+                parent_isotope.symbol
+            )));
+        }
 
-    let mut particle = Particle::new(name, mass);
-    particle.params.insert(RotConst(rot_const));
+        masses[0][i] = parent_isotope.mass.value();
+        masses[1][i] = isotope.mass.value();
+        names.push(format!("{}{}", isotope.mass_number, isotope.symbol));
+    }
 
-    Some(particle)
+    let red_mass = |[m1, m2]: [f64; 2]| m1 * m2 / (m1 + m2);
+    let rho = (red_mass(masses[0]) / red_mass(masses[1])).sqrt();
+    let rho2 = rho * rho;
+
+    let mut particle = parent.clone();
+    particle.rename(&names.concat());
+    particle
+        .insert(Mass(masses[1][0] + masses[1][1], Dalton).to(Au))
+        .insert(Composition(names));
+    particle.remove::<Mass<Au, Uncertain>>();
+
+    if let Some(rot_const) = particle.get_mut::<RotConst>() {
+        rot_const.0 *= rho2;
+    }
+    if let Some(distortion) = particle.get_mut::<CentrifugalDistortion>() {
+        distortion.0 *= rho2 * rho2;
+    }
+    if let Some(spin_rotation) = particle.get_mut::<SpinRotation>() {
+        spin_rotation.0 *= rho2;
+    }
+    if let Some(vib_consts) = particle.get_mut::<VibConsts>() {
+        vib_consts.omega_e *= rho;
+        vib_consts.omega_e_x_e *= rho2;
+    }
+
+    Ok(particle)
+}
+
+/// Creates an isotopologue of the diatomic molecule with given name, see [`isotopologue`].
+/// Returns error if the molecule is unknown or the isotopologue can not be created,
+/// e.g. for polyatomic molecules such as `"OCS"`.
+pub fn create_isotopologue(name: &str, composition: &[&str]) -> Result<Particle> {
+    let parent = create_molecule(name).ok_or_else(|| Error::Isotopologue {
+        molecule: name.to_string(),
+        message: "unknown molecule".to_string(),
+    })?;
+
+    isotopologue(&parent, composition)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        units::{
+            constants::CODATA,
+            energy_units::{Energy, MHz},
+            mass_units::{Dalton, Mass},
+            Au,
+        },
+    };
+
+    use super::{
+        create_atom, create_isotopologue, create_molecule, isotopologue, load_user_particles,
+        Composition, ElectronGFactor, ElectronSpin, HyperfineConst, NuclearGFactor, NuclearSpin,
+        RotConst, VibConsts, ALKALI_HYPERFINE_CONSTS,
+    };
+
+    #[test]
+    fn isotopologues() {
+        let krb = create_molecule("KRb").unwrap();
+        let krb_39 = create_isotopologue("KRb", &["K-39", "Rb87"]).unwrap();
+        assert_eq!(krb_39.name(), "39K87Rb");
+        assert_eq!(
+            krb_39.get::<Composition>(),
+            Some(&Composition(vec!["39K".to_string(), "87Rb".to_string()]))
+        );
+
+        let rho2 = krb.get::<RotConst>().unwrap().0 / krb_39.get::<RotConst>().unwrap().0;
+        let vib = krb.get::<VibConsts>().unwrap();
+        let vib_39 = krb_39.get::<VibConsts>().unwrap();
+        assert!((vib.omega_e / vib_39.omega_e - rho2.sqrt()).abs() < 1e-12);
+        assert!((vib.omega_e_x_e / vib_39.omega_e_x_e - rho2).abs() < 1e-12);
+        assert!(krb_39.get::<Mass<Au>>().unwrap() < krb.get::<Mass<Au>>().unwrap());
+
+        let same = isotopologue(&krb, &["40K", "87Rb"]).unwrap();
+        assert_eq!(same.get::<VibConsts>(), krb.get::<VibConsts>());
+
+        let ocs = create_molecule("OCS").unwrap();
+        for error in [
+            isotopologue(&ocs, &["16O", "13C", "32S"]),
+            isotopologue(&krb, &["40K", "133Cs"]),
+            isotopologue(&krb, &["40K"]),
+            isotopologue(&create_atom("Rb87").unwrap(), &["85Rb"]),
+        ] {
+            assert!(error.is_err());
+        }
+        assert_eq!(
+            create_isotopologue("OCS", &["16O", "13C", "32S"]).unwrap_err(),
+            Error::Isotopologue {
+                molecule: "OCS".to_string(),
+                message: "only diatomic molecules can be rescaled by the reduced mass".to_string(),
+            }
+        );
+        assert!(matches!(
+            create_isotopologue("XYZ", &["39K", "87Rb"]),
+            Err(Error::Isotopologue { .. })
+        ));
+    }
+
+    #[test]
+    fn alkali_hyperfine() {
+        let rb87 = create_atom("Rb-87").unwrap();
+        assert_eq!(rb87.get::<ElectronSpin>(), Some(&ElectronSpin(1)));
+        assert_eq!(
+            rb87.get::<ElectronGFactor>(),
+            Some(&ElectronGFactor(-2.00233113))
+        );
+        let hydrogen = create_atom("H1")
+            .unwrap()
+            .get::<ElectronGFactor>()
+            .unwrap()
+            .0;
+        assert!(hydrogen < 0.0);
+        assert!((hydrogen / CODATA.electron_g_factor - 1.0).abs() < 1e-4);
+        let a_hf = Energy(3417.341305452, MHz).to_au();
+        assert_eq!(rb87.get::<HyperfineConst>(), Some(&HyperfineConst(a_hf)));
+        let g_factor = rb87.get::<NuclearGFactor>().unwrap().0;
+        assert!((g_factor - 2.75131 / 1.5).abs() < 1e-12);
+
+        let k40 = create_atom("K40").unwrap();
+        assert!(k40.get::<HyperfineConst>().unwrap().0 < 0.0);
+
+        let sr88 = create_atom("Sr88").unwrap();
+        assert!(sr88.get::<ElectronSpin>().is_none());
+        assert!(sr88.get::<NuclearGFactor>().is_none());
+    }
+
+    #[test]
+    fn hyperfine_splittings() {
+        // Ground state hyperfine splittings ΔE in MHz.
+        let splittings = [
+            ("H1", 1420.405751768),
+            ("H2", 327.384352522),
+            ("Li6", 228.2052614),
+            ("Li7", 803.5040866),
+            ("Na23", 1771.6261288),
+            ("K39", 461.7197202),
+            ("K40", -1285.790),
+            ("K41", 254.0138704),
+            ("Rb85", 3035.732439),
+            ("Rb87", 6834.682610904),
+            ("Cs133", 9192.631770),
+        ];
+        assert_eq!(splittings.len(), ALKALI_HYPERFINE_CONSTS.len());
+
+        for (name, splitting) in splittings {
+            let atom = create_atom(name).unwrap();
+            let a_hf = atom.get::<HyperfineConst>().unwrap().0;
+            let double_spin = atom.get::<NuclearSpin>().unwrap().0 as f64;
+
+            let expected = Energy(splitting, MHz).to_au();
+            let splitting = a_hf * (double_spin + 1.0) / 2.0;
+            assert!((splitting / expected - 1.0).abs() < 1e-5, "{name}");
+        }
+    }
+
+    #[test]
+    fn user_particles() {
+        let path = std::env::temp_dir().join(format!("user_particles_{}.txt", std::process::id()));
+        std::fs::write(
+            &path,
+            "[N2_user]\nmass = 28 u\nelectron_spin = 1\n[N3_user]\nmass = 42 u",
+        )
+        .unwrap();
+        load_user_particles(&path).unwrap();
+
+        let n2 = create_molecule("N2_user").unwrap();
+        assert_eq!(n2.get::<Mass<Au>>(), Some(&Mass(28.0, Dalton).to(Au)));
+        assert_eq!(n2.get::<ElectronSpin>(), Some(&ElectronSpin(2)));
+        assert!(n2.get::<VibConsts>().is_none());
+        assert!(create_molecule("N3_user").is_some());
+
+        std::fs::write(&path, "[N4_user]\nmass = 56\n").unwrap();
+        let error = load_user_particles(&path).unwrap_err();
+        assert!(matches!(error, Error::ParticleDefinition { line: 2, .. }));
+        assert!(create_molecule("N4_user").is_none());
+
+        std::fs::remove_file(&path).unwrap();
+        let error = load_user_particles(&path).unwrap_err();
+        assert!(matches!(error, Error::ReadFile { .. }));
+        let source = std::error::Error::source(&error).unwrap();
+        assert!(source.downcast_ref::<std::io::Error>().is_some());
+    }
 }
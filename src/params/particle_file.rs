@@ -0,0 +1,363 @@
+use std::{fs, path::Path, sync::Arc};
+
+use crate::{
+    error::{Error, IoError, Result},
+    units::{
+        electric_field_units::{AnyElectricDipoleUnit, ElectricDipole},
+        energy_units::{AnyEnergyUnit, Energy},
+        magnetic_field_units::{AnyMagneticMomentUnit, MagneticMoment},
+        mass_units::{AnyMassUnit, Mass},
+        Au,
+    },
+};
+
+use super::{
+    isotopes::parse_double_spin,
+    long_range::{Charge, Polarizability, C6, C8},
+    particle::Particle,
+    particle_factory::{
+        AtomicNumber, CentrifugalDistortion, Composition, ElectronGFactor, ElectronSpin,
+        HyperfineConst, MassNumber, NaturalAbundance, NuclearGFactor, NuclearMagneticMoment,
+        NuclearSpin, PermanentDipole, QuadrupoleConst, QuantumStatistics, RotConst, SpinRotation,
+        VibConsts,
+    },
+    Params,
+};
+
+/// Parses particle definitions given as sections of `key = value` lines,
+/// values may be optionally quoted as in TOML.
+/// Every particle requires a `mass`, the other recognized keys are
+/// `atomic_number`, `mass_number`, `nuclear_spin`, `electron_spin`, `abundance`,
+/// `nuclear_magnetic_moment`, `statistics` (`boson` or `fermion`), `rot_const`,
+/// `centrifugal_distortion`, `omega_e` together with `omega_e_x_e`, `spin_rotation`,
+/// `dipole`, `composition` (comma separated isotopes), `hyperfine_const`, `quadrupole_const`,
+/// `electron_g_factor`, `nuclear_g_factor`, `charge` and atomic units valued
+/// `polarizability`, homonuclear `c6` and `c8`.
+/// # Examples
+/// ```
+/// use quantum::params::{particle_file::parse_particles, particle_factory::{NuclearSpin, RotConst}};
+/// use quantum::units::{energy_units::{Energy, GHz}, mass_units::{Mass, Dalton}, Au};
+/// let particles = parse_particles(r#"
+/// [Rb87_model]  # model particle
+/// mass = 86.909 u
+/// nuclear_spin = 3/2
+///
+/// ["K-Rb model"]
+/// mass = "126.87 u"
+/// rot_const = 1.114 GHz
+/// "#).unwrap();
+///
+/// assert_eq!(particles[0].name(), "Rb87_model");
+/// assert_eq!(particles[0].get::<NuclearSpin>(), Some(&NuclearSpin(3)));
+/// assert_eq!(particles[1].get::<Mass<Au>>(), Some(&Mass(126.87, Dalton).to(Au)));
+/// assert_eq!(particles[1].get::<RotConst>().unwrap().0, Energy(1.114, GHz).to_au());
+///
+/// let error = parse_particles("[X]\nmass = 1 u\nspin = 1").unwrap_err();
+/// assert_eq!(error.to_string(), "line 3: unknown key \"spin\"");
+/// ```
+pub fn parse_particles(source: &str) -> Result<Vec<Particle>> {
+    let mut particles = Vec::new();
+    let mut current: Option<Definition> = None;
+
+    for (index, line) in source.lines().enumerate() {
+        let line_number = index + 1;
+        let error = |message: String| Error::ParticleDefinition {
+            line: line_number,
+            message,
+        };
+
+        let line = strip_comment(line).trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[') {
+            let name = name
+                .strip_suffix(']')
+                .ok_or_else(|| error("unclosed section header".to_string()))?;
+            let name = unquote(name.trim());
+            if name.is_empty() {
+                return Err(error("empty particle name".to_string()));
+            }
+            if particles.iter().any(|x: &Particle| x.name() == name)
+                || current.as_ref().is_some_and(|x| x.name == name)
+            {
+                return Err(error(format!("duplicate particle \"{name}\"")));
+            }
+
+            if let Some(definition) = current.take() {
+                particles.push(definition.finish()?);
+            }
+            current = Some(Definition::new(name, line_number));
+
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| error(format!("expected \"key = value\", found \"{line}\"")))?;
+        let definition = current
+            .as_mut()
+            .ok_or_else(|| error("parameter outside of particle section".to_string()))?;
+
+        definition
+            .set(key.trim(), unquote(value.trim()))
+            .map_err(error)?;
+    }
+
+    if let Some(definition) = current {
+        particles.push(definition.finish()?);
+    }
+
+    Ok(particles)
+}
+
+/// Reads particle definitions from the file, see [`parse_particles`] for the format.
+pub fn read_particles(path: impl AsRef<Path>) -> Result<Vec<Particle>> {
+    let path = path.as_ref();
+    let source = fs::read_to_string(path).map_err(|error| Error::ReadFile {
+        path: path.display().to_string(),
+        source: IoError(Arc::new(error)),
+    })?;
+
+    parse_particles(&source)
+}
+
+struct Definition {
+    name: String,
+    line: usize,
+    mass: Option<Mass<Au>>,
+    omega_e: Option<f64>,
+    omega_e_x_e: Option<f64>,
+    params: Params,
+}
+
+impl Definition {
+    fn new(name: &str, line: usize) -> Self {
+        Self {
+            name: name.to_string(),
+            line,
+            mass: None,
+            omega_e: None,
+            omega_e_x_e: None,
+            params: Params::default(),
+        }
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> std::result::Result<(), String> {
+        let params = &mut self.params;
+
+        match key {
+            "mass" => self.mass = Some(parse_quantity::<Mass<AnyMassUnit>>(value)?.to(Au)),
+            "atomic_number" => {
+                params.insert(AtomicNumber(parse_integer(value)?));
+            }
+            "mass_number" => {
+                params.insert(MassNumber(parse_integer(value)?));
+            }
+            "nuclear_spin" => {
+                params.insert(NuclearSpin(parse_spin(value)?));
+            }
+            "electron_spin" => {
+                params.insert(ElectronSpin(parse_spin(value)?));
+            }
+            "abundance" => {
+                params.insert(NaturalAbundance(parse_float(value)?));
+            }
+            "nuclear_magnetic_moment" => {
+                let moment = parse_quantity::<MagneticMoment<AnyMagneticMomentUnit>>(value)?;
+                params.insert(NuclearMagneticMoment(moment.to(Au)));
+            }
+            "statistics" => {
+                let statistics = match value {
+                    "boson" => QuantumStatistics::Boson,
+                    "fermion" => QuantumStatistics::Fermion,
+                    _ => return Err(format!("unknown statistics \"{value}\"")),
+                };
+                params.insert(statistics);
+            }
+            "rot_const" => {
+                params.insert(RotConst(parse_energy(value)?));
+            }
+            "centrifugal_distortion" => {
+                params.insert(CentrifugalDistortion(parse_energy(value)?));
+            }
+            "spin_rotation" => {
+                params.insert(SpinRotation(parse_energy(value)?));
+            }
+            "omega_e" => self.omega_e = Some(parse_energy(value)?),
+            "omega_e_x_e" => self.omega_e_x_e = Some(parse_energy(value)?),
+            "dipole" => {
+                let dipole = parse_quantity::<ElectricDipole<AnyElectricDipoleUnit>>(value)?;
+                params.insert(PermanentDipole(dipole.to(Au)));
+            }
+            "composition" => {
+                let composition = value.split(',').map(|x| x.trim().to_string()).collect();
+                params.insert(Composition(composition));
+            }
+            "hyperfine_const" => {
+                params.insert(HyperfineConst(parse_energy(value)?));
+            }
+            "quadrupole_const" => {
+                params.insert(QuadrupoleConst(parse_energy(value)?));
+            }
+            "electron_g_factor" => {
+                params.insert(ElectronGFactor(parse_float(value)?));
+            }
+            "nuclear_g_factor" => {
+                params.insert(NuclearGFactor(parse_float(value)?));
+            }
+            "charge" => {
+                let charge = value
+                    .parse()
+                    .map_err(|_| format!("invalid integer \"{value}\""))?;
+                params.insert(Charge(charge));
+            }
+            "polarizability" => {
+                params.insert(Polarizability(parse_float(value)?));
+            }
+            "c6" => {
+                params.insert(C6(parse_float(value)?));
+            }
+            "c8" => {
+                params.insert(C8(parse_float(value)?));
+            }
+            _ => return Err(format!("unknown key \"{key}\"")),
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Particle> {
+        let error = |message: String| Error::ParticleDefinition {
+            line: self.line,
+            message,
+        };
+
+        let mass = self
+            .mass
+            .ok_or_else(|| error(format!("particle \"{}\" is missing mass", self.name)))?;
+
+        let mut particle = Particle::new(&self.name, mass);
+        particle.params.params.extend(self.params.params);
+
+        match (self.omega_e, self.omega_e_x_e) {
+            (Some(omega_e), Some(omega_e_x_e)) => {
+                particle.insert(VibConsts {
+                    omega_e,
+                    omega_e_x_e,
+                });
+            }
+            (None, None) => (),
+            _ => {
+                return Err(error(format!(
+                    "particle \"{}\" requires both omega_e and omega_e_x_e",
+                    self.name
+                )))
+            }
+        }
+
+        Ok(particle)
+    }
+}
+
+/// Strips the `#` comment, `#` inside quotes is a part of the name or value.
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+    for (index, c) in line.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..index],
+            _ => (),
+        }
+    }
+
+    line
+}
+
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|x| x.strip_suffix('"'))
+        .unwrap_or(value)
+}
+
+fn parse_quantity<Q: std::str::FromStr<Err = crate::units::ParseQuantityError>>(
+    value: &str,
+) -> std::result::Result<Q, String> {
+    value.parse().map_err(|error| format!("{error}"))
+}
+
+fn parse_energy(value: &str) -> std::result::Result<f64, String> {
+    Ok(parse_quantity::<Energy<AnyEnergyUnit>>(value)?.to_au())
+}
+
+fn parse_integer(value: &str) -> std::result::Result<u32, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid integer \"{value}\""))
+}
+
+fn parse_float(value: &str) -> std::result::Result<f64, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid number \"{value}\""))
+}
+
+fn parse_spin(value: &str) -> std::result::Result<u32, String> {
+    parse_double_spin(value).ok_or_else(|| format!("invalid spin \"{value}\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        params::particle_factory::{ElectronSpin, QuantumStatistics, VibConsts},
+    };
+
+    use super::parse_particles;
+
+    #[test]
+    fn particle_definitions() {
+        let source = "
+            [CaF_model]   # custom molecule
+            mass = 59.0 u
+            electron_spin = 1/2
+            statistics = boson
+            omega_e = 581.1 cm-1
+            omega_e_x_e = 2.74 cm-1
+        ";
+        let particles = parse_particles(source).unwrap();
+        assert_eq!(particles.len(), 1);
+        assert_eq!(particles[0].get::<ElectronSpin>(), Some(&ElectronSpin(1)));
+        assert_eq!(
+            particles[0].get::<QuantumStatistics>(),
+            Some(&QuantumStatistics::Boson)
+        );
+        assert!(particles[0].get::<VibConsts>().is_some());
+
+        let source = "
+            [\"K#1\"] # quoted hash
+            mass = \"39 u\" # comment
+        ";
+        let particles = parse_particles(source).unwrap();
+        assert_eq!(particles[0].name(), "K#1");
+    }
+
+    #[test]
+    fn malformed_definitions() {
+        let line = |source: &str| match parse_particles(source).unwrap_err() {
+            Error::ParticleDefinition { line, .. } => line,
+            error => panic!("unexpected error {error}"),
+        };
+
+        assert_eq!(line("mass = 1 u"), 1);
+        assert_eq!(line("[A]\nmass = 1 u\n\n[A]\nmass = 1 u"), 4);
+        assert_eq!(line("[A]\nmass = 1 parsec"), 2);
+        assert_eq!(line("[A]\nmass 1 u"), 2);
+        assert_eq!(line("[A]\nmass = 1 u\nnuclear_spin = 1/3"), 3);
+        assert_eq!(line("\n[A]\nomega_e = 1 K"), 2);
+        assert_eq!(line("[A]\nmass = 1 u\nomega_e = 1 K"), 1);
+        assert_eq!(line("[A"), 1);
+    }
+}
@@ -1,61 +1,465 @@
 use std::ops::{Deref, DerefMut};
 
 use crate::{
-    params::Params,
-    units::{energy_units::Energy, mass_units::Mass, Au, Unit},
+    error::{Error, Result},
+    params::{param_label, Param, Params},
+    units::{
+        distance_units::Distance, energy_units::Energy, mass_units::Mass, uncertain::Uncertain, Au,
+        DynUnit,
+    },
 };
 
-use super::particle::Particle;
+use super::{
+    jacobi::{Arrangement, Jacobi},
+    long_range::{self, Charge, C3, C4, C6, C8},
+    particle::Particle,
+    particle_factory::{ElectronSpin, NuclearSpin},
+};
+
+/// Ratio of the mean scattering length to the van der Waals length `4π / Γ(1/4)²`.
+const MEAN_SCATTERING_RATIO: f64 = 0.955_977_594_972_25;
 
 /// Struct to hold information about a particle composition.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Particles {
     particles: Vec<Particle>,
     pub params: Params,
+    /// Parameters computed from the particles, recomputed when the composition changes
+    /// unless they were replaced in `params`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    derived: Params,
 }
 
 impl Particles {
     /// Creates two particle composition with given collision energy inserted inside `internals` as "energy".
-    pub fn new_pair<U: Unit>(
+    /// # Panics
+    /// Panics if any of the particles does not have a mass, see [`Particles::try_new_pair`].
+    pub fn new_pair<U: DynUnit>(
         first_particle: Particle,
         second_particle: Particle,
         energy: Energy<U>,
     ) -> Self {
-        let mass1 = first_particle.params.get::<Mass<Au>>().unwrap().value();
-        let mass2 = second_particle.params.get::<Mass<Au>>().unwrap().value();
-
-        let inverse_reduced_mass: f64 = 1.0 / mass1 + 1.0 / mass2;
+        Self::try_new_pair(first_particle, second_particle, energy)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
 
-        let mut params = Params::default();
-        params
-            .insert(energy.to(Au))
-            .insert(Mass(1. / inverse_reduced_mass, Au));
+    /// Creates two particle composition with given collision energy inserted inside `internals` as "energy".
+    /// Long-range coefficients of the pair are inserted if they can be computed
+    /// from the particle parameters, see [`long_range`].
+    /// Returns error if any of the particles does not have a mass.
+    pub fn try_new_pair<U: DynUnit>(
+        first_particle: Particle,
+        second_particle: Particle,
+        energy: Energy<U>,
+    ) -> Result<Self> {
+        let mut particles = Self::try_new_custom(vec![first_particle, second_particle])?;
+        particles.params.insert(energy.to(Au));
 
-        Self {
-            particles: vec![first_particle, second_particle],
-            params,
-        }
+        Ok(particles)
     }
 
-    /// Mutably borrows particles with given name.
+    /// Mutably borrows all particles.
+    /// Changing the particle masses does not update the stored reduced mass.
     pub fn particle_mut(&mut self) -> &mut [Particle] {
         &mut self.particles
     }
 
+    /// Borrows all particles.
+    pub fn particles(&self) -> &[Particle] {
+        &self.particles
+    }
+
+    /// Returns the particle at given index.
+    pub fn particle(&self, index: usize) -> Option<&Particle> {
+        self.particles.get(index)
+    }
+
+    /// Returns the first particle with given name.
+    /// # Examples
+    /// ```
+    /// use quantum::params::{particle_factory::create_atom, particles::Particles};
+    /// use quantum::units::energy_units::{Energy, Kelvin};
+    /// let particles = Particles::new_pair(
+    ///     create_atom("Rb87").unwrap(),
+    ///     create_atom("K40").unwrap(),
+    ///     Energy(1e-7, Kelvin),
+    /// );
+    /// assert_eq!(particles.particle_named("K40").unwrap().name(), "K40");
+    /// assert!(particles.particle_named("Li6").is_none());
+    /// ```
+    pub fn particle_named(&self, name: &str) -> Option<&Particle> {
+        self.particles
+            .iter()
+            .find(|particle| particle.name() == name)
+    }
+
+    /// Mutably borrows the first particle with given name.
+    /// Changing the particle mass does not update the stored reduced mass.
+    pub fn particle_named_mut(&mut self, name: &str) -> Option<&mut Particle> {
+        self.particles
+            .iter_mut()
+            .find(|particle| particle.name() == name)
+    }
+
+    /// Returns the index of the first particle with given name.
+    pub fn position(&self, name: &str) -> Option<usize> {
+        self.particles
+            .iter()
+            .position(|particle| particle.name() == name)
+    }
+
+    /// Iterates over the particles.
+    pub fn iter(&self) -> std::slice::Iter<'_, Particle> {
+        self.particles.iter()
+    }
+
+    /// Adds the particle and recomputes the reduced mass and the pair long-range coefficients,
+    /// parameters replaced by the user are kept.
+    /// Returns error if any of the particles does not have a mass,
+    /// in which case the composition is unchanged.
+    /// # Examples
+    /// ```
+    /// use quantum::params::{particle_factory::create_atom, particles::Particles};
+    /// use quantum::units::energy_units::{Energy, Kelvin};
+    /// let mut particles = Particles::new_pair(
+    ///     create_atom("Rb87").unwrap(),
+    ///     create_atom("K40").unwrap(),
+    ///     Energy(1e-7, Kelvin),
+    /// );
+    /// let pair_red_mass = particles.red_mass();
+    ///
+    /// particles.add_particle(create_atom("Li6").unwrap()).unwrap();
+    /// assert_eq!(particles.particles().len(), 3);
+    ///
+    /// let removed = particles.remove_particle("Li6").unwrap();
+    /// assert_eq!(removed.name(), "Li6");
+    /// assert!((particles.red_mass() / pair_red_mass - 1.0).abs() < 1e-15);
+    /// ```
+    pub fn add_particle(&mut self, particle: Particle) -> Result<()> {
+        self.particles.push(particle);
+        if let Err(error) = masses(&self.particles) {
+            self.particles.pop();
+
+            return Err(error);
+        }
+
+        self.update_composition()
+    }
+
+    /// Removes the first particle with given name
+    /// and recomputes the reduced mass and the pair long-range coefficients as in [`Particles::add_particle`].
+    /// If the remaining particles are missing a mass, the computed reduced mass is removed from the parameters.
+    pub fn remove_particle(&mut self, name: &str) -> Option<Particle> {
+        let index = self.position(name)?;
+        let particle = self.particles.remove(index);
+        let _ = self.update_composition();
+
+        Some(particle)
+    }
+
+    /// Returns the total charge in units of the elementary charge,
+    /// particles without the [`Charge`] parameter are neutral.
+    pub fn total_charge(&self) -> i32 {
+        self.particles
+            .iter()
+            .map(|particle| particle.get::<Charge>().map_or(0, |charge| charge.0))
+            .sum()
+    }
+
+    /// Returns the maximal total electron spin obtained by coupling
+    /// the [`ElectronSpin`] of all particles, particles without it have zero spin.
+    pub fn max_electron_spin(&self) -> ElectronSpin {
+        ElectronSpin(
+            self.particles
+                .iter()
+                .map(|particle| particle.get::<ElectronSpin>().map_or(0, |spin| spin.0))
+                .sum(),
+        )
+    }
+
+    /// Returns the maximal total nuclear spin obtained by coupling
+    /// the [`NuclearSpin`] of all particles, particles without it have zero spin.
+    pub fn max_nuclear_spin(&self) -> NuclearSpin {
+        NuclearSpin(
+            self.particles
+                .iter()
+                .map(|particle| particle.get::<NuclearSpin>().map_or(0, |spin| spin.0))
+                .sum(),
+        )
+    }
+
     /// Creates a particle composition given a vector of particles.
+    /// # Panics
+    /// Panics if any of the particles does not have a mass, see [`Particles::try_new_custom`].
     pub fn new_custom(particles: Vec<Particle>) -> Self {
-        let inverse_reduced_mass = particles.iter().fold(0.0, |acc, particle| {
-            acc + 1.0 / particle.params.get::<Mass<Au>>().unwrap().value()
-        });
+        Self::try_new_custom(particles).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Creates a particle composition given a vector of particles,
+    /// with the reduced mass being the hyperspherical reduced mass, see [`Jacobi::hyperspherical_red_mass`],
+    /// or the particle mass for a single particle.
+    /// Long-range coefficients of a pair are inserted if they can be computed
+    /// from the particle parameters, see [`long_range`].
+    /// Returns error if any of the particles does not have a mass.
+    pub fn try_new_custom(particles: Vec<Particle>) -> Result<Self> {
+        let mut particles = Self {
+            particles,
+            params: Params::default(),
+            derived: Params::default(),
+        };
+        particles.update_composition()?;
+
+        Ok(particles)
+    }
+
+    /// Recomputes the reduced masses and the pair long-range coefficients,
+    /// keeping the parameters that no longer hold the previously computed values.
+    fn update_composition(&mut self) -> Result<()> {
+        self.remove_derived::<Mass<Au>>();
+        self.remove_derived::<Mass<Au, Uncertain>>();
+        self.remove_derived::<C3>();
+        self.remove_derived::<C4>();
+        self.remove_derived::<C6>();
+        self.remove_derived::<C8>();
+
+        let masses = masses(&self.particles)?;
+        let Some(&first) = masses.first() else {
+            return Ok(());
+        };
+        let red_mass = Jacobi::new(&masses)
+            .hyperspherical_red_mass()
+            .unwrap_or(first);
+        self.derived.insert(Mass(red_mass, Au));
+        if let Some(red_mass) = uncertain_red_mass(&self.particles) {
+            self.derived.insert(red_mass);
+        }
+
+        if let [first, second] = &self.particles[..] {
+            long_range::insert_pair_long_range(&mut self.derived, first, second);
+        }
+
+        self.insert_derived::<Mass<Au>>();
+        self.insert_derived::<Mass<Au, Uncertain>>();
+        self.insert_derived::<C3>();
+        self.insert_derived::<C4>();
+        self.insert_derived::<C6>();
+        self.insert_derived::<C8>();
+
+        Ok(())
+    }
+
+    /// Removes the computed parameter unless it was replaced by the user.
+    fn remove_derived<T: Param + Clone + PartialEq>(&mut self) {
+        if let Some(derived) = self.derived.get::<T>() {
+            if self.params.get::<T>() == Some(derived) {
+                self.params.remove::<T>();
+            }
+        }
+        self.derived.remove::<T>();
+    }
+
+    /// Inserts the computed parameter unless the user already set it.
+    fn insert_derived<T: Param + Clone + PartialEq>(&mut self) {
+        let Some(derived) = self.derived.get::<T>() else {
+            return;
+        };
+
+        if self.params.contains::<T>() {
+            self.derived.remove::<T>();
+        } else {
+            self.params.insert(derived.clone());
+        }
+    }
+
+    /// Returns the total mass of all particles or error if any of them does not have a mass.
+    pub fn total_mass(&self) -> Result<Mass<Au>> {
+        Ok(Mass(masses(&self.particles)?.iter().sum(), Au))
+    }
 
-        let mut params = Params::default();
-        params.insert(Mass(1. / inverse_reduced_mass, Au));
+    /// Returns the Jacobi coordinates of the particles coupled in given order of particle indices.
+    /// # Panics
+    /// Panics if any index is out of bounds.
+    pub fn jacobi(&self, order: &[usize]) -> Result<Jacobi> {
+        let masses = order
+            .iter()
+            .map(|&index| Ok(self.particles[index].mass()?.value()))
+            .collect::<Result<Vec<f64>>>()?;
 
-        Self { particles, params }
+        Ok(Jacobi::new(&masses))
+    }
+
+    /// Returns the Jacobi coordinates of a three particle composition in given arrangement channel
+    /// or error if the composition does not consist of three particles.
+    /// # Examples
+    /// ```
+    /// use quantum::params::{jacobi::Arrangement, particle_factory::create_atom, particles::Particles};
+    /// let particles = Particles::new_custom(vec![
+    ///     create_atom("Li7").unwrap(),
+    ///     create_atom("Rb87").unwrap(),
+    ///     create_atom("K40").unwrap(),
+    /// ]);
+    /// // Li + RbK channel
+    /// let jacobi = particles.arrangement(Arrangement(0)).unwrap();
+    /// let pair = particles.jacobi(&[1, 2]).unwrap();
+    /// assert_eq!(jacobi.red_masses()[0], pair.red_masses()[0]);
+    /// let total_mass = particles.total_mass().unwrap().value();
+    /// assert!((jacobi.total_mass() / total_mass - 1.0).abs() < 1e-15);
+    /// ```
+    pub fn arrangement(&self, arrangement: Arrangement) -> Result<Jacobi> {
+        if self.particles.len() != 3 {
+            return Err(Error::ParticleCount {
+                expected: 3,
+                found: self.particles.len(),
+            });
+        }
+
+        self.jacobi(&arrangement.order())
     }
 
     /// Gets the reduced mass.
+    /// # Panics
+    /// Panics if the reduced mass was removed from the parameters.
     pub fn red_mass(&self) -> f64 {
-        self.params.get::<Mass<Au>>().unwrap().value()
+        self.try_red_mass()
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Gets the reduced mass or error if it was removed from the parameters.
+    pub fn try_red_mass(&self) -> Result<f64> {
+        self.params
+            .get::<Mass<Au>>()
+            .map(|mass| mass.value())
+            .ok_or(Error::MissingParam {
+                param: param_label::<Mass<Au>>(),
+            })
+    }
+
+    /// Returns the van der Waals length `R_vdW = (2μC₆)^(1/4) / 2`.
+    /// # Examples
+    /// ```
+    /// use quantum::params::{particle_factory::create_atom, particles::Particles};
+    /// use quantum::units::energy_units::{Energy, Kelvin, MicroKelvin};
+    /// let particles = Particles::new_pair(
+    ///     create_atom("Rb87").unwrap(),
+    ///     create_atom("Rb87").unwrap(),
+    ///     Energy(1e-7, Kelvin),
+    /// );
+    /// let r_vdw = particles.van_der_waals_length().unwrap();
+    /// assert!((r_vdw.to_au() - 82.6).abs() < 0.1);
+    ///
+    /// let a_bar = particles.mean_scattering_length().unwrap();
+    /// assert!((a_bar.to_au() / r_vdw.to_au() - 0.956).abs() < 1e-3);
+    ///
+    /// let e_vdw = particles.van_der_waals_energy().unwrap();
+    /// assert!((e_vdw.to(MicroKelvin).value() - 292.2).abs() < 0.5);
+    /// ```
+    pub fn van_der_waals_length(&self) -> Result<Distance<Au>> {
+        let c6 = self.try_param::<C6>()?.0;
+
+        Ok(Distance(
+            (2.0 * self.try_red_mass()? * c6).powf(0.25) / 2.0,
+            Au,
+        ))
+    }
+
+    /// Returns the mean scattering length `ā = 4π / Γ(1/4)² R_vdW` of Gribakin and Flambaum.
+    pub fn mean_scattering_length(&self) -> Result<Distance<Au>> {
+        Ok(MEAN_SCATTERING_RATIO * self.van_der_waals_length()?)
+    }
+
+    /// Returns the van der Waals energy `E_vdW = ħ² / (2μR_vdW²)`.
+    pub fn van_der_waals_energy(&self) -> Result<Energy<Au>> {
+        let r_vdw = self.van_der_waals_length()?.to_au();
+
+        Ok(Energy(
+            1.0 / (2.0 * self.try_red_mass()? * r_vdw * r_vdw),
+            Au,
+        ))
+    }
+
+    /// Returns the characteristic length of the ion-atom interaction `R* = (2μC₄)^(1/2)`.
+    pub fn ion_atom_length(&self) -> Result<Distance<Au>> {
+        let c4 = self.try_param::<C4>()?.0;
+
+        Ok(Distance((2.0 * self.try_red_mass()? * c4).sqrt(), Au))
+    }
+
+    /// Returns the characteristic energy of the ion-atom interaction `E* = ħ² / (2μR*²)`.
+    pub fn ion_atom_energy(&self) -> Result<Energy<Au>> {
+        let r_star = self.ion_atom_length()?.to_au();
+
+        Ok(Energy(
+            1.0 / (2.0 * self.try_red_mass()? * r_star * r_star),
+            Au,
+        ))
+    }
+
+    fn try_param<T: 'static>(&self) -> Result<&T> {
+        self.params.get::<T>().ok_or(Error::MissingParam {
+            param: param_label::<T>(),
+        })
+    }
+
+    /// Gets the reduced mass with propagated uncertainty
+    /// if all particles were created with mass uncertainty.
+    pub fn red_mass_uncertain(&self) -> Option<Uncertain> {
+        self.params
+            .get::<Mass<Au, Uncertain>>()
+            .map(|mass| mass.value())
+    }
+}
+
+fn masses(particles: &[Particle]) -> Result<Vec<f64>> {
+    particles
+        .iter()
+        .map(|particle| Ok(particle.mass()?.value()))
+        .collect()
+}
+
+/// Hyperspherical reduced mass `(m₁m₂…mₙ / M)^(1 / (N - 1))` with the uncertainty
+/// propagated from the particle masses, accounting for their correlation with the total mass `M`.
+fn uncertain_red_mass(particles: &[Particle]) -> Option<Mass<Au, Uncertain>> {
+    let masses = particles
+        .iter()
+        .map(|particle| {
+            particle
+                .params
+                .get::<Mass<Au, Uncertain>>()
+                .map(|mass| mass.value())
+        })
+        .collect::<Option<Vec<Uncertain>>>()?;
+    match masses[..] {
+        [] => return None,
+        [mass] => return Some(Mass(mass, Au)),
+        _ => (),
+    }
+
+    let values: Vec<f64> = masses.iter().map(|mass| mass.value()).collect();
+    let total_mass: f64 = values.iter().sum();
+    let red_mass = Jacobi::new(&values).hyperspherical_red_mass()?;
+
+    let n = (masses.len() - 1) as f64;
+    let relative_error = masses
+        .iter()
+        .map(|mass| (1.0 / mass.value() - 1.0 / total_mass) * mass.error())
+        .map(|error| error * error)
+        .sum::<f64>()
+        .sqrt()
+        / n;
+
+    Some(Mass(
+        Uncertain::new(red_mass, red_mass * relative_error),
+        Au,
+    ))
+}
+
+impl<'a> IntoIterator for &'a Particles {
+    type Item = &'a Particle;
+    type IntoIter = std::slice::Iter<'a, Particle>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.particles.iter()
     }
 }
 
@@ -72,3 +476,230 @@ impl DerefMut for Particles {
         &mut self.params
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        params::{
+            jacobi::Arrangement,
+            long_range::{C4, C6, C8},
+            particle::Particle,
+            particle_factory::{create_atom, ElectronSpin, NuclearSpin},
+            Params,
+        },
+        units::{
+            energy_units::{Energy, Kelvin},
+            mass_units::{Dalton, Mass},
+            uncertain::Uncertain,
+            Au,
+        },
+    };
+
+    use super::Particles;
+
+    #[test]
+    fn uncertain_red_mass() {
+        let first =
+            Particle::new_uncertain("Rb87", Mass(Uncertain::new(86.90918053, 6e-9), Dalton));
+        let second =
+            Particle::new_uncertain("K40", Mass(Uncertain::new(39.96399817, 6e-8), Dalton));
+        let particles = Particles::new_pair(first, second, Energy(1e-7, Kelvin));
+
+        let red_mass = particles.red_mass_uncertain().unwrap();
+        assert!((red_mass.value() / particles.red_mass() - 1.0).abs() < 1e-12);
+
+        let (m1, m2): (f64, f64) = (86.90918053, 39.96399817);
+        let expected_error = (m2 * m2 * 6e-9).hypot(m1 * m1 * 6e-8) / (m1 + m2).powi(2);
+        let expected_error = Mass(expected_error, Dalton).to_au();
+        assert!((red_mass.error() / expected_error - 1.0).abs() < 1e-6);
+
+        let particles = Particles::new_pair(
+            Particle::new("Rb87", Mass(86.90918053, Dalton)),
+            Particle::new("K40", Mass(39.96399817, Dalton)),
+            Energy(1e-7, Kelvin),
+        );
+        assert!(particles.red_mass_uncertain().is_none());
+    }
+
+    #[test]
+    fn three_body_red_mass() {
+        let masses = [7.016, 86.909, 39.964];
+        let particles = Particles::new_custom(
+            masses
+                .iter()
+                .map(|&mass| Particle::new_uncertain("X", Mass(Uncertain::new(mass, 1e-3), Dalton)))
+                .collect(),
+        );
+
+        let total: f64 = masses.iter().sum();
+        let expected = Mass((masses.iter().product::<f64>() / total).sqrt(), Dalton).to_au();
+        assert!((particles.red_mass() / expected - 1.0).abs() < 1e-12);
+
+        let step = Mass(1e-6, Dalton).to_au();
+        let expected_error = (0..masses.len())
+            .map(|index| {
+                let mut shifted: Vec<f64> =
+                    masses.iter().map(|&m| Mass(m, Dalton).to_au()).collect();
+                shifted[index] += step;
+                let shifted = Particles::new_custom(
+                    shifted
+                        .into_iter()
+                        .map(|mass| Particle::new("X", Mass(mass, Au)))
+                        .collect(),
+                );
+                let derivative = (shifted.red_mass() - particles.red_mass()) / step;
+
+                derivative * Mass(1e-3, Dalton).to_au()
+            })
+            .map(|error| error * error)
+            .sum::<f64>()
+            .sqrt();
+
+        let red_mass = particles.red_mass_uncertain().unwrap();
+        assert!((red_mass.error() / expected_error - 1.0).abs() < 1e-4);
+
+        let pair = Particles::new_custom(particles.particles[..2].to_vec());
+        assert!(matches!(
+            pair.arrangement(Arrangement(0)),
+            Err(Error::ParticleCount {
+                expected: 3,
+                found: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn missing_mass() {
+        let mut massless = Particle::new("Rb87", Mass(86.90918053, Dalton));
+        massless.params = Params::default();
+
+        let result = Particles::try_new_pair(
+            Particle::new("K40", Mass(39.96399817, Dalton)),
+            massless,
+            Energy(1e-7, Kelvin),
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "particle \"Rb87\" is missing parameter mass"
+        );
+
+        let mut particles = Particles::new_pair(
+            Particle::new("Rb87", Mass(86.90918053, Dalton)),
+            Particle::new("K40", Mass(39.96399817, Dalton)),
+            Energy(1e-7, Kelvin),
+        );
+        assert!(particles.try_red_mass().is_ok());
+        particles.remove::<Mass<Au>>();
+        assert_eq!(
+            particles.try_red_mass(),
+            Err(Error::MissingParam { param: "mass" })
+        );
+        assert_eq!(
+            particles.van_der_waals_length().unwrap_err().to_string(),
+            "missing parameter c6"
+        );
+    }
+
+    #[test]
+    fn parallel_clones() {
+        let particles = Particles::new_pair(
+            Particle::new("Rb87", Mass(86.90918053, Dalton)),
+            Particle::new("K40", Mass(39.96399817, Dalton)),
+            Energy(1e-7, Kelvin),
+        );
+        let red_mass = particles.red_mass();
+
+        let handles: Vec<_> = (1..=4)
+            .map(|i| {
+                let mut particles = particles.clone();
+                std::thread::spawn(move || {
+                    particles.insert(Energy(i as f64 * 1e-7, Kelvin).to(Au));
+                    particles.red_mass()
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), red_mass);
+        }
+        assert!(format!("{particles:?}").contains("Rb87"));
+    }
+
+    #[test]
+    fn composition_queries() {
+        let mut particles = Particles::new_pair(
+            create_atom("Rb87").unwrap(),
+            create_atom("Rb87").unwrap(),
+            Energy(1e-7, Kelvin),
+        );
+        assert!(particles.get::<C8>().is_some());
+        assert_eq!(particles.max_electron_spin(), ElectronSpin(2));
+        assert_eq!(particles.max_nuclear_spin(), NuclearSpin(6));
+        assert_eq!(particles.total_charge(), 0);
+        assert_eq!(particles.position("Rb87"), Some(0));
+        assert_eq!(particles.iter().count(), 2);
+
+        let ion = create_atom("Yb174").unwrap().with_charge(1);
+        assert!(particles.add_particle(ion).is_ok());
+        assert_eq!(particles.total_charge(), 1);
+        assert!(particles.get::<C6>().is_none());
+        assert!(particles.get::<C8>().is_none());
+
+        particles.remove_particle("Rb87").unwrap();
+        assert_eq!(particles.particle(1).unwrap().name(), "Yb174");
+        assert!(particles.get::<C4>().is_some());
+        let expected = Particles::new_pair(
+            create_atom("Rb87").unwrap(),
+            create_atom("Yb174").unwrap(),
+            Energy(1e-7, Kelvin),
+        );
+        assert_eq!(particles.red_mass(), expected.red_mass());
+        assert!(particles.get::<Energy<Au>>().is_some());
+
+        let mut massless = Particle::new("X", Mass(1.0, Dalton));
+        massless.params = Params::default();
+        assert!(particles.add_particle(massless).is_err());
+        assert_eq!(particles.particles().len(), 2);
+
+        particles.remove_particle("Rb87").unwrap();
+        assert_eq!(
+            particles.red_mass(),
+            particles
+                .particle_named("Yb174")
+                .unwrap()
+                .get::<Mass<Au>>()
+                .unwrap()
+                .value()
+        );
+        assert!(particles.remove_particle("Rb87").is_none());
+
+        particles.remove_particle("Yb174").unwrap();
+        assert!(particles.try_red_mass().is_err());
+    }
+
+    #[test]
+    fn user_params_kept() {
+        let mut particles = Particles::new_pair(
+            create_atom("Rb87").unwrap(),
+            create_atom("Rb87").unwrap(),
+            Energy(1e-7, Kelvin),
+        );
+        let c8 = *particles.get::<C8>().unwrap();
+        particles.insert(C6(100.0));
+
+        particles.add_particle(create_atom("Li6").unwrap()).unwrap();
+        assert_eq!(particles.get::<C6>(), Some(&C6(100.0)));
+        assert!(particles.get::<C8>().is_none());
+
+        particles.remove_particle("Li6").unwrap();
+        assert_eq!(particles.get::<C6>(), Some(&C6(100.0)));
+        assert_eq!(particles.get::<C8>(), Some(&c8));
+
+        let red_mass = particles.red_mass();
+        particles.particle_mut()[0].params = Params::default();
+        assert!(particles.add_particle(create_atom("Li6").unwrap()).is_err());
+        assert_eq!(particles.particles().len(), 2);
+        assert_eq!(particles.red_mass(), red_mass);
+    }
+}
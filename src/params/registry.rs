@@ -0,0 +1,303 @@
+use std::{
+    any::{Any, TypeId},
+    collections::{BTreeMap, HashMap},
+    sync::{OnceLock, RwLock},
+};
+
+use serde::{
+    de::{DeserializeOwned, Error as _},
+    ser::{Error as _, SerializeMap},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use serde_json::Value;
+
+use crate::units::{energy_units::Energy, mass_units::Mass, uncertain::Uncertain, Au};
+
+use super::{
+    builtin_name,
+    long_range::{Charge, Polarizability, C3, C4, C6, C8},
+    particle_factory::{
+        AtomicNumber, CentrifugalDistortion, Composition, ElectronGFactor, ElectronSpin,
+        HyperfineConst, MassNumber, NaturalAbundance, NuclearGFactor, NuclearMagneticMoment,
+        NuclearSpin, PermanentDipole, QuadrupoleConst, QuantumStatistics, RotConst, SpinRotation,
+        VibConsts,
+    },
+    Param, ParamKey, Params,
+};
+
+type SerializeFn = fn(&dyn Any) -> Result<Value, serde_json::Error>;
+type DeserializeFn = fn(Value) -> Result<Box<dyn Param>, serde_json::Error>;
+
+struct Entry {
+    name: &'static str,
+    key: fn(Option<&str>) -> ParamKey,
+    serialize: SerializeFn,
+    deserialize: DeserializeFn,
+}
+
+#[derive(Default)]
+struct Registry {
+    entries: HashMap<TypeId, Entry>,
+    names: HashMap<&'static str, TypeId>,
+}
+
+impl Registry {
+    fn insert<T: Param + Serialize + DeserializeOwned>(&mut self, name: &'static str) {
+        if let Some(previous) = self.names.insert(name, TypeId::of::<T>()) {
+            self.entries.remove(&previous);
+        }
+
+        self.entries.insert(
+            TypeId::of::<T>(),
+            Entry {
+                name,
+                key: ParamKey::of::<T>,
+                serialize: serialize_erased::<T>,
+                deserialize: deserialize_erased::<T>,
+            },
+        );
+    }
+
+    /// Registers the parameter type defined by the crate under its name, see [`builtin_names`].
+    fn insert_builtin<T: Param + Serialize + DeserializeOwned>(&mut self) {
+        let name = builtin_name(TypeId::of::<T>()).expect("built-in parameter type has a name");
+
+        self.insert::<T>(name)
+    }
+}
+
+fn serialize_erased<T: Serialize + 'static>(value: &dyn Any) -> Result<Value, serde_json::Error> {
+    serde_json::to_value(value.downcast_ref::<T>().unwrap())
+}
+
+fn deserialize_erased<T: Param + DeserializeOwned>(
+    value: Value,
+) -> Result<Box<dyn Param>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<T>(value)?))
+}
+
+fn registry() -> &'static RwLock<Registry> {
+    static REGISTRY: OnceLock<RwLock<Registry>> = OnceLock::new();
+
+    REGISTRY.get_or_init(|| {
+        let mut registry = Registry::default();
+        registry.insert_builtin::<Mass<Au>>();
+        registry.insert_builtin::<Mass<Au, Uncertain>>();
+        registry.insert_builtin::<Energy<Au>>();
+        registry.insert_builtin::<RotConst>();
+        registry.insert_builtin::<AtomicNumber>();
+        registry.insert_builtin::<MassNumber>();
+        registry.insert_builtin::<NuclearSpin>();
+        registry.insert_builtin::<NaturalAbundance>();
+        registry.insert_builtin::<NuclearMagneticMoment>();
+        registry.insert_builtin::<QuantumStatistics>();
+        registry.insert_builtin::<CentrifugalDistortion>();
+        registry.insert_builtin::<VibConsts>();
+        registry.insert_builtin::<SpinRotation>();
+        registry.insert_builtin::<PermanentDipole>();
+        registry.insert_builtin::<ElectronSpin>();
+        registry.insert_builtin::<Composition>();
+        registry.insert_builtin::<HyperfineConst>();
+        registry.insert_builtin::<QuadrupoleConst>();
+        registry.insert_builtin::<ElectronGFactor>();
+        registry.insert_builtin::<NuclearGFactor>();
+        registry.insert_builtin::<Charge>();
+        registry.insert_builtin::<Polarizability>();
+        registry.insert_builtin::<C3>();
+        registry.insert_builtin::<C4>();
+        registry.insert_builtin::<C6>();
+        registry.insert_builtin::<C8>();
+
+        RwLock::new(registry)
+    })
+}
+
+/// Registers parameter type `T` under `name`, so that it is serialized
+/// and deserialized as part of [`Params`].
+/// Registering a different type with the same name replaces the previous registration.
+/// # Panics
+/// Panics if `name` contains `:`, which separates the registered name
+/// from the parameter name of named parameters.
+/// # Examples
+/// ```
+/// use quantum::params::{registry::register, Params};
+/// #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+/// struct ScatteringLength(f64);
+///
+/// register::<ScatteringLength>("scattering_length");
+///
+/// let mut params = Params::default();
+/// params.insert(ScatteringLength(10.0));
+/// let serialized = serde_json::to_string(&params).unwrap();
+/// assert_eq!(serialized, r#"{"scattering_length":10.0}"#);
+/// ```
+pub fn register<T: Param + Serialize + DeserializeOwned>(name: &'static str) {
+    assert!(
+        !name.contains(':'),
+        "registered parameter name \"{name}\" can not contain ':'"
+    );
+
+    registry().write().unwrap().insert::<T>(name)
+}
+
+/// Name under which the parameter type is registered.
+pub(crate) fn registered_name(type_id: TypeId) -> Option<&'static str> {
+    registry()
+        .read()
+        .unwrap()
+        .entries
+        .get(&type_id)
+        .map(|entry| entry.name)
+}
+
+/// Serializes as a map from registered names to values,
+/// parameters inserted with a name are keyed as `"registered_name:name"`.
+/// Fails if any of the stored parameter types is not registered.
+impl Serialize for Params {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let registry = registry().read().unwrap();
+
+        let mut values = BTreeMap::new();
+        for (key, value) in &self.params {
+            let entry = registry.entries.get(&key.type_id).ok_or_else(|| {
+                S::Error::custom(format!(
+                    "parameter type {} is not registered",
+                    key.type_name
+                ))
+            })?;
+            let value = (entry.serialize)((**value).as_any()).map_err(S::Error::custom)?;
+
+            let name = match &key.name {
+                Some(name) => format!("{}:{name}", entry.name),
+                None => entry.name.to_string(),
+            };
+            values.insert(name, value);
+        }
+
+        let mut map = serializer.serialize_map(Some(values.len()))?;
+        for (name, value) in values {
+            map.serialize_entry(&name, &value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Params {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = HashMap::<String, Value>::deserialize(deserializer)?;
+        let registry = registry().read().unwrap();
+
+        let mut params = Params::default();
+        for (key, value) in values {
+            let (type_name, name) = match key.split_once(':') {
+                Some((type_name, name)) => (type_name, Some(name)),
+                None => (key.as_str(), None),
+            };
+
+            let entry = registry
+                .names
+                .get(type_name)
+                .map(|type_id| &registry.entries[type_id])
+                .ok_or_else(|| {
+                    D::Error::custom(format!("unregistered parameter \"{type_name}\""))
+                })?;
+            let value = (entry.deserialize)(value).map_err(D::Error::custom)?;
+
+            params.params.insert((entry.key)(name), value);
+        }
+
+        Ok(params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::{
+        params::{
+            builtin_names, particle::Particle, particle_factory, particles::Particles, Params,
+        },
+        units::{
+            distance_units::{Angstrom, AnyDistanceUnit, Distance},
+            energy_units::{CmInv, Energy, Kelvin},
+            mass_units::{Dalton, Mass},
+            uncertain::Uncertain,
+            Au,
+        },
+    };
+
+    use super::{register, registered_name};
+
+    #[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+    struct Parameter(u32);
+
+    #[derive(Clone, Debug)]
+    struct Unregistered;
+
+    #[test]
+    #[should_panic(expected = "can not contain ':'")]
+    fn register_separator() {
+        register::<Parameter>("my:parameter");
+    }
+
+    #[test]
+    fn quantity_serde() {
+        let serialized = serde_json::to_string(&Energy(1.5, Kelvin)).unwrap();
+        assert_eq!(serialized, r#"{"value":1.5,"unit":"K"}"#);
+        let energy: Energy<Kelvin> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(energy.value(), 1.5);
+        assert!(serde_json::from_str::<Energy<CmInv>>(&serialized).is_err());
+
+        let serialized = serde_json::to_string(&Distance(2.5, Angstrom)).unwrap();
+        let distance: Distance<AnyDistanceUnit> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(distance.unit(), AnyDistanceUnit::Angstrom);
+        assert_eq!(distance.value(), 2.5);
+
+        let mass = Mass(Uncertain::new(86.909, 1e-3), Dalton);
+        let serialized = serde_json::to_string(&mass).unwrap();
+        assert!(serialized.contains(r#""unit":"u""#));
+        let deserialized: Mass<Dalton, Uncertain> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.value(), mass.value());
+    }
+
+    #[test]
+    fn params_serde() {
+        register::<Parameter>("parameter");
+
+        let mut particle = particle_factory::create_atom("Rb87").unwrap();
+        particle
+            .insert(Parameter(3))
+            .insert_named("spin", Parameter(1));
+        let serialized = serde_json::to_string(&particle).unwrap();
+
+        let particle: Particle = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(particle.name(), "Rb87");
+        assert_eq!(particle.get::<Parameter>(), Some(&Parameter(3)));
+        assert_eq!(particle.get_named::<Parameter>("spin"), Some(&Parameter(1)));
+
+        let particles = Particles::new_pair(
+            particle,
+            particle_factory::create_atom("Li6").unwrap(),
+            Energy(1e-7, Kelvin),
+        );
+        let red_mass = particles.red_mass();
+        let serialized = serde_json::to_string(&particles).unwrap();
+        let particles: Particles = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(particles.red_mass(), red_mass);
+        assert!(particles.get::<Energy<Au>>().is_some());
+        assert!(particles.get::<Mass<Au>>().is_some());
+
+        let mut params = Params::default();
+        params.insert(Unregistered);
+        assert!(serde_json::to_string(&params).is_err());
+        assert!(serde_json::from_str::<Params>(r#"{"unknown": 1.0}"#).is_err());
+    }
+
+    #[test]
+    fn builtin_names_registered() {
+        for (type_id, name) in builtin_names() {
+            assert_eq!(registered_name(type_id), Some(name));
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use std::{iter::Sum, ops::Mul};
+
+use crate::{
+    error::Result,
+    units::{
+        cross_section_units::CrossSection, energy_units::Energy, rate_units::RateCoefficient, Au,
+        DynUnit,
+    },
+};
+
+use super::particles::Particles;
+
+/// Maxwell–Boltzmann distribution of collision energies of a particle pair at given temperature,
+/// discretized with the generalized Gauss-Laguerre quadrature
+/// `∫ f(E) 2/√π (kT)^(-3/2) √E exp(-E/kT) dE ≈ Σ wᵢ f(Eᵢ)`
+/// with weights summing up to one.
+#[derive(Clone, Debug)]
+pub struct ThermalGrid {
+    temperature: Energy<Au>,
+    red_mass: f64,
+    energies: Vec<Energy<Au>>,
+    weights: Vec<f64>,
+}
+
+impl ThermalGrid {
+    /// Creates the collision energy grid with `points` quadrature points
+    /// for the particles at temperature given in energy units, e.g. `Energy(1e-6, Kelvin)`.
+    /// Returns error if the particles do not have a reduced mass.
+    /// # Panics
+    /// Panics if `points` is zero.
+    /// # Examples
+    /// ```
+    /// use quantum::params::{particle_factory::create_atom, particles::Particles, thermal::ThermalGrid};
+    /// use quantum::units::{energy_units::{Energy, Kelvin, MicroKelvin}, Au};
+    /// let particles = Particles::new_pair(
+    ///     create_atom("Rb87").unwrap(),
+    ///     create_atom("K40").unwrap(),
+    ///     Energy(1e-7, Kelvin),
+    /// );
+    /// let grid = ThermalGrid::new(&particles, Energy(1.0, MicroKelvin), 20).unwrap();
+    ///
+    /// let mean_energy: Energy<Au> = grid.average(|energy| energy);
+    /// assert!(mean_energy.approx_eq_rel(Energy(1.5, MicroKelvin), 1e-12));
+    /// ```
+    pub fn new<U: DynUnit>(
+        particles: &Particles,
+        temperature: Energy<U>,
+        points: usize,
+    ) -> Result<Self> {
+        assert!(points > 0, "thermal grid requires at least one point");
+
+        let temperature = temperature.to(Au);
+        let (nodes, weights) = gauss_laguerre(points, 0.5);
+        let energies = nodes
+            .iter()
+            .map(|&x| Energy(x * temperature.value(), Au))
+            .collect();
+
+        Ok(Self {
+            temperature,
+            red_mass: particles.try_red_mass()?,
+            energies,
+            weights,
+        })
+    }
+
+    pub fn temperature(&self) -> Energy<Au> {
+        self.temperature
+    }
+
+    /// Returns the collision energies of the quadrature points.
+    pub fn energies(&self) -> &[Energy<Au>] {
+        &self.energies
+    }
+
+    /// Returns the quadrature weights summing up to one.
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    pub fn len(&self) -> usize {
+        self.energies.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.energies.is_empty()
+    }
+
+    /// Returns the thermal average of the energy dependent observable,
+    /// e.g. `f64`, [`Uncertain`](crate::units::uncertain::Uncertain) or a quantity.
+    pub fn average<T, F>(&self, mut observable: F) -> T
+    where
+        T: Mul<f64, Output = T> + Sum,
+        F: FnMut(Energy<Au>) -> T,
+    {
+        self.energies
+            .iter()
+            .zip(&self.weights)
+            .map(|(&energy, &weight)| observable(energy) * weight)
+            .sum()
+    }
+
+    /// Returns the rate coefficient `k(T) = ⟨v σ(E)⟩` given the energy dependent cross section,
+    /// where `v = (2E / μ)^(1/2)` is the relative velocity of the pair.
+    /// # Examples
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use quantum::params::{particle_factory::create_atom, particles::Particles, thermal::ThermalGrid};
+    /// use quantum::units::{energy_units::{Energy, MicroKelvin}, cross_section_units::CrossSection, Au};
+    /// let particles = Particles::new_pair(
+    ///     create_atom("Rb87").unwrap(),
+    ///     create_atom("Rb87").unwrap(),
+    ///     Energy(1.0, MicroKelvin),
+    /// );
+    /// let temperature = Energy(1.0, MicroKelvin);
+    /// let grid = ThermalGrid::new(&particles, temperature, 40).unwrap();
+    ///
+    /// let rate = grid.rate_coefficient(|_| CrossSection(1e4, Au));
+    /// let mean_velocity = (8.0 * temperature.to_au() / (PI * particles.red_mass())).sqrt();
+    /// assert!((rate.to_au() / (1e4 * mean_velocity) - 1.0).abs() < 1e-3);
+    /// ```
+    pub fn rate_coefficient<U, F>(&self, mut cross_section: F) -> RateCoefficient<Au>
+    where
+        U: DynUnit,
+        F: FnMut(Energy<Au>) -> CrossSection<U>,
+    {
+        let rate = self.average(|energy| {
+            let velocity = (2.0 * energy.to_au() / self.red_mass).sqrt();
+
+            cross_section(energy).to_au() * velocity
+        });
+
+        RateCoefficient(rate, Au)
+    }
+}
+
+impl Particles {
+    /// Creates the Maxwell–Boltzmann collision energy grid at given temperature, see [`ThermalGrid`].
+    pub fn thermal_grid<U: DynUnit>(
+        &self,
+        temperature: Energy<U>,
+        points: usize,
+    ) -> Result<ThermalGrid> {
+        ThermalGrid::new(self, temperature, points)
+    }
+}
+
+/// Nodes and weights of the generalized Gauss-Laguerre quadrature
+/// for the weight function `x^alpha exp(-x)`, with weights normalized to one.
+fn gauss_laguerre(n: usize, alpha: f64) -> (Vec<f64>, Vec<f64>) {
+    let mut nodes = vec![0.0; n];
+    let mut weights = vec![0.0; n];
+
+    let n_f = n as f64;
+    let mut z: f64 = 0.0;
+    for i in 0..n {
+        z = match i {
+            0 => (1.0 + alpha) * (3.0 + 0.92 * alpha) / (1.0 + 2.4 * n_f + 1.8 * alpha),
+            1 => z + (15.0 + 6.25 * alpha) / (1.0 + 0.9 * alpha + 2.5 * n_f),
+            _ => {
+                let ai = (i - 1) as f64;
+                z + ((1.0 + 2.55 * ai) / (1.9 * ai) + 1.26 * ai * alpha / (1.0 + 3.5 * ai))
+                    * (z - nodes[i - 2])
+                    / (1.0 + 0.3 * alpha)
+            }
+        };
+
+        let mut derivative = 0.0;
+        let mut previous = 0.0;
+        for _ in 0..100 {
+            let (mut p1, mut p2) = (1.0, 0.0);
+            for j in 1..=n {
+                let p3 = p2;
+                p2 = p1;
+                let j = j as f64;
+                p1 = ((2.0 * j - 1.0 + alpha - z) * p2 - (j - 1.0 + alpha) * p3) / j;
+            }
+            derivative = (n_f * p1 - (n_f + alpha) * p2) / z;
+            previous = p2;
+
+            let step = p1 / derivative;
+            z -= step;
+            if step.abs() <= 1e-15 * z.abs() {
+                break;
+            }
+        }
+
+        nodes[i] = z;
+        weights[i] = -1.0 / (derivative * n_f * previous);
+    }
+
+    let norm: f64 = weights.iter().sum();
+    weights.iter_mut().for_each(|weight| *weight /= norm);
+
+    (nodes, weights)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::gauss_laguerre;
+
+    #[test]
+    fn gauss_laguerre_moments() {
+        // ∫ x^k x^(1/2) exp(-x) dx / Γ(3/2) = Γ(k + 3/2) / Γ(3/2)
+        let (nodes, weights) = gauss_laguerre(12, 0.5);
+        let mut expected = 1.0;
+        for k in 0..10 {
+            let moment: f64 = nodes.iter().zip(&weights).map(|(x, w)| w * x.powi(k)).sum();
+            assert!((moment / expected - 1.0).abs() < 1e-10, "{k}: {moment}");
+
+            expected *= k as f64 + 1.5;
+        }
+
+        let (nodes, weights) = gauss_laguerre(1, 0.5);
+        assert!((nodes[0] - 1.5).abs() < 1e-14);
+        assert_eq!(weights, [1.0]);
+    }
+}
@@ -1,6 +1,64 @@
+#[cfg(feature = "params")]
+use crate::{
+    error::Result,
+    params::{
+        particle::Particle,
+        particle_factory::{ElectronSpin, NuclearSpin},
+    },
+};
+
+use super::state::State;
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct DoubleSpin(pub u32, pub i32);
 
+impl DoubleSpin {
+    /// Returns all doubled projections `-s, -s + 2, ..., s` of the doubled spin `s`.
+    pub fn projections(double_spin: u32) -> Vec<i32> {
+        (-(double_spin as i32)..=double_spin as i32)
+            .step_by(2)
+            .collect()
+    }
+}
+
+/// Creates the state of all projections of the doubled spin
+/// labelled by the variant constructed from the doubled spin.
+pub fn spin_state<T: Copy>(variant: impl FnOnce(u32) -> T, double_spin: u32) -> State<T, i32> {
+    State::new(variant(double_spin), DoubleSpin::projections(double_spin))
+}
+
+/// Creates the state of all projections of the particle [`ElectronSpin`],
+/// see [`spin_state`].
+/// # Examples
+/// ```
+/// use quantum::{params::particle_factory::create_atom, states::spins::electron_spin_state};
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// enum StateIds {
+///     ElectronSpin(u32),
+/// }
+///
+/// let rb87 = create_atom("Rb87").unwrap();
+/// let state = electron_spin_state(&rb87, StateIds::ElectronSpin).unwrap();
+/// assert_eq!(state.size(), 2);
+/// ```
+#[cfg(feature = "params")]
+pub fn electron_spin_state<T: Copy>(
+    particle: &Particle,
+    variant: impl FnOnce(u32) -> T,
+) -> Result<State<T, i32>> {
+    Ok(spin_state(variant, particle.try_get::<ElectronSpin>()?.0))
+}
+
+/// Creates the state of all projections of the particle [`NuclearSpin`],
+/// see [`spin_state`].
+#[cfg(feature = "params")]
+pub fn nuclear_spin_state<T: Copy>(
+    particle: &Particle,
+    variant: impl FnOnce(u32) -> T,
+) -> Result<State<T, i32>> {
+    Ok(spin_state(variant, particle.try_get::<NuclearSpin>()?.0))
+}
+
 pub struct SpinOperators;
 
 impl SpinOperators {
@@ -1,19 +1,258 @@
+/// Generates enum of units of dimension `$dim` chosen at runtime that dispatches to the marker units
+/// and parses them from the listed symbols,
+/// e.g. for units read from a command line argument or a config file.
+/// The listed marker units, except for the leading [`Au`], are marked as units of `$dim`.
+macro_rules! any_unit {
+    (
+        $(#[$meta:meta])*
+        $name:ident: $dim:ty {
+            Au => [$($au_symbol:literal),+],
+            $($unit:ident => [$($symbol:literal),+]),+ $(,)?
+        }
+    ) => {
+        $(impl $crate::units::dimensions::UnitOf<$dim> for $unit {})+
+
+        any_unit!(@enum $(#[$meta])* $name: $dim { Au => [$($au_symbol),+], $($unit => [$($symbol),+]),+ });
+    };
+    (
+        @enum
+        $(#[$meta:meta])*
+        $name:ident: $dim:ty { $($unit:ident => [$($symbol:literal),+]),+ }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+        pub enum $name {
+            $($unit),+
+        }
+
+        impl $crate::units::dimensions::UnitOf<$dim> for $name {}
+
+        impl $crate::units::DynUnit for $name {
+            fn to_au(&self, value: f64) -> f64 {
+                match self {
+                    $($name::$unit => $unit.to_au(value)),+
+                }
+            }
+
+            fn symbol(&self) -> &'static str {
+                match self {
+                    $($name::$unit => $unit.symbol()),+
+                }
+            }
+
+            fn from_symbol(symbol: &str) -> Option<Self> {
+                match symbol {
+                    $(symbol if symbol == $unit.symbol() => Some($name::$unit),)+
+                    _ => None,
+                }
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name::Au
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = $crate::units::ParseQuantityError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s.trim() {
+                    $($($symbol)|+ => Ok($name::$unit),)+
+                    unit => Err($crate::units::ParseQuantityError::UnknownUnit(unit.to_string())),
+                }
+            }
+        }
+
+        $(
+            impl From<$unit> for $name {
+                fn from(_: $unit) -> Self {
+                    $name::$unit
+                }
+            }
+        )+
+    };
+}
+
+pub mod constants;
+pub mod cross_section_units;
+pub mod dimensions;
 pub mod distance_units;
+pub mod electric_field_units;
 pub mod energy_units;
+pub mod frequency_units;
+pub mod grid;
+pub mod magnetic_field_units;
 pub mod mass_units;
+pub mod quantity;
+pub mod rate_units;
+pub mod si_prefix;
+pub mod spectroscopy;
+pub mod time_units;
+pub mod uncertain;
+
+use std::{
+    error::Error,
+    fmt::{Alignment, Display, Formatter, Write},
+};
 
 /// Trait for units that can be converted to atomic units.
 pub trait Unit: Copy + Clone {
     const TO_AU_MUL: f64;
+    /// Symbol shown when the quantity is displayed, units without a symbol display only the value.
+    const SYMBOL: &'static str = "";
+}
+
+/// Trait for units whose conversion to atomic units is resolved at runtime.
+/// It is implemented for every [`Unit`] and for the runtime unit enums
+/// such as [`energy_units::AnyEnergyUnit`].
+///
+/// Runtime units have no compile time multiplier, so they do not implement [`Unit`]
+/// ```compile_fail
+/// use quantum::units::{energy_units::AnyEnergyUnit, Unit};
+/// fn multiplier<U: Unit>() -> f64 {
+///     U::TO_AU_MUL
+/// }
+/// let _ = multiplier::<AnyEnergyUnit>();
+/// ```
+pub trait DynUnit: Copy + Clone {
+    fn to_au(&self, value: f64) -> f64;
+
+    fn symbol(&self) -> &'static str;
 
+    /// Returns the unit with given symbol if it can be represented by this unit type.
+    fn from_symbol(symbol: &str) -> Option<Self>
+    where
+        Self: Default;
+}
+
+impl<U: Unit> DynUnit for U {
     fn to_au(&self, value: f64) -> f64 {
         value * Self::TO_AU_MUL
     }
+
+    fn symbol(&self) -> &'static str {
+        Self::SYMBOL
+    }
+
+    fn from_symbol(symbol: &str) -> Option<Self>
+    where
+        Self: Default,
+    {
+        let unit = Self::default();
+
+        (unit.symbol() == symbol).then_some(unit)
+    }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone, Default)]
 pub struct Au;
 
 impl Unit for Au {
     const TO_AU_MUL: f64 = 1.0;
+    const SYMBOL: &'static str = "a.u.";
+}
+
+/// Error returned when parsing a unit or a value with unit from a string fails.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseQuantityError {
+    /// Input string is empty.
+    Empty,
+    /// Numerical part of the input could not be parsed.
+    InvalidValue(String),
+    /// Unit symbol is not recognized for the given quantity.
+    UnknownUnit(String),
+}
+
+impl Display for ParseQuantityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseQuantityError::Empty => write!(f, "cannot parse quantity from empty string"),
+            ParseQuantityError::InvalidValue(value) => write!(f, "invalid value \"{value}\""),
+            ParseQuantityError::UnknownUnit(unit) => write!(f, "unknown unit \"{unit}\""),
+        }
+    }
+}
+
+impl Error for ParseQuantityError {}
+
+/// Writes the formatted value followed by the unit symbol, if there is any,
+/// respecting the width, fill and alignment of the formatter, left aligned by default.
+/// The `+` flag prints the sign of positive values and the `0` flag pads the value
+/// with zeros after the sign, overriding the fill and alignment as for numbers.
+pub(crate) fn pad_with_symbol(
+    f: &mut Formatter<'_>,
+    value: &str,
+    symbol: &str,
+) -> std::fmt::Result {
+    let sign = match value.chars().next() {
+        Some(sign @ ('-' | '+')) => sign.to_string(),
+        _ if f.sign_plus() => "+".to_string(),
+        _ => String::new(),
+    };
+    let digits = value.trim_start_matches(['-', '+']);
+    let suffix = match symbol {
+        "" => String::new(),
+        symbol => format!(" {symbol}"),
+    };
+
+    let length = sign.chars().count() + digits.chars().count() + suffix.chars().count();
+    let padding = f.width().map_or(0, |width| width.saturating_sub(length));
+
+    if f.sign_aware_zero_pad() {
+        return write!(f, "{sign}{}{digits}{suffix}", "0".repeat(padding));
+    }
+
+    let (before, after) = match f.align() {
+        Some(Alignment::Right) => (padding, 0),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Left) | None => (0, padding),
+    };
+
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    write!(f, "{sign}{digits}{suffix}")?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+
+    Ok(())
+}
+
+/// Splits string of the form "1.5 K" or "1.5K" into parsed value and unit symbol.
+pub(crate) fn split_value_unit(s: &str) -> Result<(f64, &str), ParseQuantityError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(ParseQuantityError::Empty);
+    }
+
+    let bytes = s.as_bytes();
+    let mut split = bytes.len();
+    for (i, &c) in bytes.iter().enumerate() {
+        let is_exponent = (c == b'e' || c == b'E')
+            && i > 0
+            && match bytes.get(i + 1) {
+                Some(b'+') | Some(b'-') => bytes.get(i + 2).is_some_and(u8::is_ascii_digit),
+                Some(next) => next.is_ascii_digit(),
+                None => false,
+            };
+
+        if !(c.is_ascii_digit() || c == b'.' || c == b'+' || c == b'-' || is_exponent) {
+            split = i;
+            break;
+        }
+    }
+
+    let (value, unit) = match split {
+        0 => s.split_once(char::is_whitespace).unwrap_or((s, "")),
+        _ => s.split_at(split),
+    };
+    let value = value
+        .parse::<f64>()
+        .map_err(|_| ParseQuantityError::InvalidValue(value.to_string()))?;
+
+    Ok((value, unit.trim()))
 }
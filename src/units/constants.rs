@@ -0,0 +1,92 @@
+/// Set of physical constants in SI units used to derive atomic unit conversions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Constants {
+    /// Bohr radius in m.
+    pub bohr_radius: f64,
+    /// Hartree energy in J.
+    pub hartree: f64,
+    /// Electron mass in kg.
+    pub electron_mass: f64,
+    /// Atomic mass unit in kg.
+    pub atomic_mass_unit: f64,
+    /// Boltzmann constant in J/K.
+    pub boltzmann: f64,
+    /// Planck constant in J s.
+    pub planck: f64,
+    /// Speed of light in m/s.
+    pub speed_of_light: f64,
+    /// Elementary charge in C.
+    pub elementary_charge: f64,
+    /// Avogadro constant in 1/mol.
+    pub avogadro: f64,
+    /// Bohr magneton in J/T.
+    pub bohr_magneton: f64,
+    /// Nuclear magneton in J/T.
+    pub nuclear_magneton: f64,
+    /// Electron g-factor, negative so that the magnetic moment is μ = g μ_B S.
+    pub electron_g_factor: f64,
+}
+
+pub const CODATA_2018: Constants = Constants {
+    bohr_radius: 5.29177210903e-11,
+    hartree: 4.3597447222071e-18,
+    electron_mass: 9.1093837015e-31,
+    atomic_mass_unit: 1.66053906660e-27,
+    boltzmann: 1.380649e-23,
+    planck: 6.62607015e-34,
+    speed_of_light: 299792458.0,
+    elementary_charge: 1.602176634e-19,
+    avogadro: 6.02214076e23,
+    bohr_magneton: 9.2740100783e-24,
+    nuclear_magneton: 5.0507837461e-27,
+    electron_g_factor: -2.00231930436256,
+};
+
+pub const CODATA_2022: Constants = Constants {
+    bohr_radius: 5.29177210544e-11,
+    hartree: 4.3597447222060e-18,
+    electron_mass: 9.1093837139e-31,
+    atomic_mass_unit: 1.66053906892e-27,
+    boltzmann: 1.380649e-23,
+    planck: 6.62607015e-34,
+    speed_of_light: 299792458.0,
+    elementary_charge: 1.602176634e-19,
+    avogadro: 6.02214076e23,
+    bohr_magneton: 9.2740100657e-24,
+    nuclear_magneton: 5.0507837393e-27,
+    electron_g_factor: -2.00231930436092,
+};
+
+/// Constant set used by all units, CODATA 2018 by default and CODATA 2022 with `codata2022` feature.
+#[cfg(not(feature = "codata2022"))]
+pub const CODATA: Constants = CODATA_2018;
+
+/// Constant set used by all units, CODATA 2018 by default and CODATA 2022 with `codata2022` feature.
+#[cfg(feature = "codata2022")]
+pub const CODATA: Constants = CODATA_2022;
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::{Constants, CODATA_2018, CODATA_2022};
+
+    fn hartree_from_bohr_radius(constants: &Constants) -> f64 {
+        let hbar = constants.planck / (2.0 * PI);
+
+        hbar * hbar / (constants.electron_mass * constants.bohr_radius.powi(2))
+    }
+
+    #[test]
+    fn consistency() {
+        for constants in [CODATA_2018, CODATA_2022] {
+            let hartree = hartree_from_bohr_radius(&constants);
+            assert!((hartree / constants.hartree - 1.0).abs() < 1e-9);
+
+            let hbar = constants.planck / (2.0 * PI);
+            let bohr_magneton =
+                constants.elementary_charge * hbar / (2.0 * constants.electron_mass);
+            assert!((bohr_magneton / constants.bohr_magneton - 1.0).abs() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use super::{
+    constants::CODATA,
+    dimensions::{AreaDim, UnitOf},
+    quantity::Quantity,
+    Au, Unit,
+};
+
+/// Quantity representing cross section values, alias of [`Quantity`] with area dimension.
+/// # Examples
+/// ```
+/// use quantum::units::{Au, cross_section_units::{CrossSection, AngstromSquared, CmSquared}};
+/// let cross_section = CrossSection(1.0, AngstromSquared);
+/// assert!(cross_section.to(CmSquared).approx_eq_rel(CrossSection(1e-16, CmSquared), 1e-12));
+///
+/// let cross_section_au = CrossSection(1.0, Au).to(CmSquared);
+/// assert!((cross_section_au.value() - 2.8002852e-17).abs() < 1e-24);
+/// ```
+pub type CrossSection<U, T = f64> = Quantity<AreaDim, U, T>;
+
+/// Creates cross section value in given unit.
+#[allow(non_snake_case)]
+pub fn CrossSection<U: UnitOf<AreaDim>, T>(value: T, unit: U) -> CrossSection<U, T> {
+    Quantity::new(value, unit)
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct CmSquared;
+
+impl Unit for CmSquared {
+    const TO_AU_MUL: f64 = 1e-4 / (CODATA.bohr_radius * CODATA.bohr_radius);
+    const SYMBOL: &'static str = "cm²";
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct AngstromSquared;
+
+impl Unit for AngstromSquared {
+    const TO_AU_MUL: f64 = 1e-20 / (CODATA.bohr_radius * CODATA.bohr_radius);
+    const SYMBOL: &'static str = "Å²";
+}
+
+any_unit!(
+    /// Cross section unit chosen at runtime.
+    AnyCrossSectionUnit: AreaDim {
+        Au => ["au", "a.u.", "bohr^2", "a0^2"],
+        CmSquared => ["cm^2", "cm2", "cm²"],
+        AngstromSquared => ["Å^2", "A^2", "Å²", "angstrom^2"],
+    }
+);
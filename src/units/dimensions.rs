@@ -0,0 +1,67 @@
+use std::{
+    marker::PhantomData,
+    ops::{Add, Div, Mul, Sub},
+};
+
+use typenum::{Diff, Sum, N1, N2, N3, P1, P2, P3, Z0};
+
+use super::{Au, DynUnit};
+
+/// Physical dimension given by the exponents of mass `M`, length `L`, time `T` and current `I`
+/// as type level integers, so that dimension checks happen at compile time.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Dim<M, L, T, I>(PhantomData<(M, L, T, I)>);
+
+impl<M1, L1, T1, I1, M2, L2, T2, I2> Mul<Dim<M2, L2, T2, I2>> for Dim<M1, L1, T1, I1>
+where
+    M1: Add<M2>,
+    L1: Add<L2>,
+    T1: Add<T2>,
+    I1: Add<I2>,
+{
+    type Output = Dim<Sum<M1, M2>, Sum<L1, L2>, Sum<T1, T2>, Sum<I1, I2>>;
+
+    fn mul(self, _: Dim<M2, L2, T2, I2>) -> Self::Output {
+        Dim(PhantomData)
+    }
+}
+
+impl<M1, L1, T1, I1, M2, L2, T2, I2> Div<Dim<M2, L2, T2, I2>> for Dim<M1, L1, T1, I1>
+where
+    M1: Sub<M2>,
+    L1: Sub<L2>,
+    T1: Sub<T2>,
+    I1: Sub<I2>,
+{
+    type Output = Dim<Diff<M1, M2>, Diff<L1, L2>, Diff<T1, T2>, Diff<I1, I2>>;
+
+    fn div(self, _: Dim<M2, L2, T2, I2>) -> Self::Output {
+        Dim(PhantomData)
+    }
+}
+
+/// Marker of units measuring quantities of dimension `D`,
+/// [`super::quantity::Quantity`] can only be created in such units.
+/// Atomic units measure every dimension.
+/// ```compile_fail
+/// use quantum::units::{distance_units::Distance, energy_units::Kelvin};
+/// let _ = Distance(1.0, Kelvin);
+/// ```
+pub trait UnitOf<D>: DynUnit {}
+
+impl<D> UnitOf<D> for Au {}
+
+pub type Dimensionless = Dim<Z0, Z0, Z0, Z0>;
+pub type MassDim = Dim<P1, Z0, Z0, Z0>;
+pub type LengthDim = Dim<Z0, P1, Z0, Z0>;
+pub type TimeDim = Dim<Z0, Z0, P1, Z0>;
+pub type AreaDim = Dim<Z0, P2, Z0, Z0>;
+pub type RateCoefficientDim = Dim<Z0, P3, N1, Z0>;
+pub type FrequencyDim = Dim<Z0, Z0, N1, Z0>;
+pub type EnergyDim = Dim<P1, P2, N2, Z0>;
+pub type ActionDim = Dim<P1, P2, N1, Z0>;
+pub type MomentOfInertiaDim = Dim<P1, P2, Z0, Z0>;
+pub type MagneticFieldDim = Dim<P1, Z0, N2, N1>;
+pub type MagneticMomentDim = Dim<Z0, P2, Z0, P1>;
+pub type ElectricFieldDim = Dim<P1, P1, N3, N1>;
+pub type ElectricDipoleDim = Dim<Z0, P1, P1, P1>;
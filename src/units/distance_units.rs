@@ -1,6 +1,11 @@
-use super::Unit;
+use super::{
+    constants::CODATA,
+    dimensions::{LengthDim, UnitOf},
+    quantity::Quantity,
+    Au, Unit,
+};
 
-/// Struct for representing distance unit values
+/// Quantity representing distance values, alias of [`Quantity`] with length dimension.
 /// # Examples
 /// ```
 /// use quantum::units::{Au, distance_units::{Distance, Angstrom}};
@@ -8,30 +13,87 @@ use super::Unit;
 /// let distance_au = distance_ang.to(Au);
 /// let distance = distance_ang.to_au();
 /// assert!(distance == distance_au.value());
-#[derive(Debug, Copy, Clone)]
-pub struct Distance<U: Unit>(pub f64, pub U);
+/// ```
+/// Conversions between SI and atomic units
+/// ```
+/// use quantum::units::distance_units::{Bohr, Distance, Meter, Nanometer, Picometer};
+/// let distance = Distance(1.0, Nanometer);
+/// assert!(distance.to(Picometer).approx_eq_rel(Distance(1000.0, Picometer), 1e-12));
+/// assert!(distance.to(Meter).approx_eq_rel(Distance(1e-9, Meter), 1e-12));
+/// assert!((distance.to(Bohr).value() - 18.8972612).abs() < 1e-6);
+/// ```
+pub type Distance<U, T = f64> = Quantity<LengthDim, U, T>;
 
-impl<U: Unit> Distance<U> {
-    pub fn to_au(&self) -> f64 {
-        self.1.to_au(self.0)
-    }
+/// Creates distance value in given unit.
+#[allow(non_snake_case)]
+pub fn Distance<U: UnitOf<LengthDim>, T>(value: T, unit: U) -> Distance<U, T> {
+    Quantity::new(value, unit)
+}
 
-    pub fn to<V: Unit>(&self, unit: V) -> Distance<V> {
-        Distance(self.1.to_au(self.0) / unit.to_au(1.0), unit)
-    }
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Bohr;
 
-    pub fn value(&self) -> f64 {
-        self.0
-    }
+impl Unit for Bohr {
+    const TO_AU_MUL: f64 = 1.0;
+    const SYMBOL: &'static str = "bohr";
+}
 
-    pub fn unit(&self) -> U {
-        self.1
-    }
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Meter;
+
+impl Unit for Meter {
+    const TO_AU_MUL: f64 = 1.0 / CODATA.bohr_radius;
+    const SYMBOL: &'static str = "m";
 }
 
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone, Default)]
 pub struct Angstrom;
 
 impl Unit for Angstrom {
-    const TO_AU_MUL: f64 = 1.88973;
+    const TO_AU_MUL: f64 = 1e-10 / CODATA.bohr_radius;
+    const SYMBOL: &'static str = "Å";
 }
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Nanometer;
+
+impl Unit for Nanometer {
+    const TO_AU_MUL: f64 = 1e-9 / CODATA.bohr_radius;
+    const SYMBOL: &'static str = "nm";
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Micrometer;
+
+impl Unit for Micrometer {
+    const TO_AU_MUL: f64 = 1e-6 / CODATA.bohr_radius;
+    const SYMBOL: &'static str = "μm";
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Picometer;
+
+impl Unit for Picometer {
+    const TO_AU_MUL: f64 = 1e-12 / CODATA.bohr_radius;
+    const SYMBOL: &'static str = "pm";
+}
+
+any_unit!(
+    /// Distance unit chosen at runtime.
+    /// # Examples
+    /// ```
+    /// use quantum::units::distance_units::{AnyDistanceUnit, Distance, Angstrom};
+    /// let distance: Distance<AnyDistanceUnit> = "2.5 Å".parse().unwrap();
+    /// assert_eq!(distance.unit(), AnyDistanceUnit::Angstrom);
+    /// assert_eq!(distance.to_au(), Distance(2.5, Angstrom).to_au());
+    /// ```
+    AnyDistanceUnit: LengthDim {
+        Au => ["au", "a.u."],
+        Bohr => ["bohr", "a0"],
+        Meter => ["m"],
+        Angstrom => ["Å", "A", "angstrom"],
+        Nanometer => ["nm"],
+        Micrometer => ["um", "μm", "µm"],
+        Picometer => ["pm"],
+    }
+);
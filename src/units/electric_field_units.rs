@@ -0,0 +1,88 @@
+use super::{
+    constants::CODATA,
+    dimensions::{ElectricDipoleDim, ElectricFieldDim, UnitOf},
+    energy_units::Energy,
+    quantity::Quantity,
+    Au, DynUnit, Unit,
+};
+
+/// Atomic unit of electric field E_h / (e a0) in V/m.
+const AU_FIELD: f64 = CODATA.hartree / (CODATA.elementary_charge * CODATA.bohr_radius);
+/// Atomic unit of electric dipole moment e a0 in C m.
+const AU_DIPOLE: f64 = CODATA.elementary_charge * CODATA.bohr_radius;
+
+/// Quantity representing electric field values, alias of [`Quantity`] with electric field dimension.
+/// # Examples
+/// ```
+/// use quantum::units::{
+///     electric_field_units::{Debye, ElectricDipole, ElectricField, KVPerCm},
+///     energy_units::{Energy, MHz},
+/// };
+/// let field = ElectricField(1.0, KVPerCm);
+/// let energy = field.coupling_energy(ElectricDipole(1.0, Debye));
+/// assert!(energy.approx_eq(Energy(503.4, MHz), Energy(0.1, MHz)));
+/// ```
+pub type ElectricField<U, T = f64> = Quantity<ElectricFieldDim, U, T>;
+
+/// Creates electric field value in given unit.
+#[allow(non_snake_case)]
+pub fn ElectricField<U: UnitOf<ElectricFieldDim>, T>(value: T, unit: U) -> ElectricField<U, T> {
+    Quantity::new(value, unit)
+}
+
+impl<U: DynUnit> ElectricField<U> {
+    /// Returns the energy d E of the electric `dipole` in this field.
+    pub fn coupling_energy<V: DynUnit>(&self, dipole: ElectricDipole<V>) -> Energy<Au> {
+        *self * dipole
+    }
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct VPerCm;
+
+impl Unit for VPerCm {
+    const TO_AU_MUL: f64 = 100.0 / AU_FIELD;
+    const SYMBOL: &'static str = "V/cm";
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct KVPerCm;
+
+impl Unit for KVPerCm {
+    const TO_AU_MUL: f64 = 1e3 * VPerCm::TO_AU_MUL;
+    const SYMBOL: &'static str = "kV/cm";
+}
+
+/// Quantity representing electric dipole moment values, alias of [`Quantity`] with dipole moment dimension.
+pub type ElectricDipole<U, T = f64> = Quantity<ElectricDipoleDim, U, T>;
+
+/// Creates electric dipole moment value in given unit.
+#[allow(non_snake_case)]
+pub fn ElectricDipole<U: UnitOf<ElectricDipoleDim>, T>(value: T, unit: U) -> ElectricDipole<U, T> {
+    Quantity::new(value, unit)
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Debye;
+
+impl Unit for Debye {
+    const TO_AU_MUL: f64 = 1e-21 / CODATA.speed_of_light / AU_DIPOLE;
+    const SYMBOL: &'static str = "D";
+}
+
+any_unit!(
+    /// Electric field unit chosen at runtime.
+    AnyElectricFieldUnit: ElectricFieldDim {
+        Au => ["au", "a.u."],
+        VPerCm => ["V/cm"],
+        KVPerCm => ["kV/cm"],
+    }
+);
+
+any_unit!(
+    /// Electric dipole unit chosen at runtime.
+    AnyElectricDipoleUnit: ElectricDipoleDim {
+        Au => ["au", "a.u."],
+        Debye => ["D", "Debye"],
+    }
+);
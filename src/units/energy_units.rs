@@ -1,64 +1,194 @@
-use super::Unit;
+use super::{
+    constants::CODATA,
+    dimensions::{EnergyDim, UnitOf},
+    quantity::Quantity,
+    Au, Unit,
+};
 
-/// Struct for representing energy unit values
+/// Thermochemical calorie in J.
+const CALORIE: f64 = 4.184;
+
+/// Quantity representing energy values, alias of [`Quantity`] with energy dimension.
 /// # Examples
 /// ```
 /// use quantum::units::energy_units::{Energy, Kelvin, CmInv};
 /// let energy_kelvin = Energy(1.0, Kelvin);
 /// let energy_cm_inv = energy_kelvin.to(CmInv);
 /// let energy = energy_kelvin.to_au();
-#[derive(Debug, Copy, Clone)]
-pub struct Energy<U: Unit>(pub f64, pub U);
+pub type Energy<U, T = f64> = Quantity<EnergyDim, U, T>;
 
-impl<U: Unit> Energy<U> {
-    pub fn to_au(&self) -> f64 {
-        self.1.to_au(self.0)
-    }
+/// Creates energy value in given unit.
+#[allow(non_snake_case)]
+pub fn Energy<U: UnitOf<EnergyDim>, T>(value: T, unit: U) -> Energy<U, T> {
+    Quantity::new(value, unit)
+}
 
-    pub fn to<V: Unit>(&self, unit: V) -> Energy<V> {
-        Energy(self.1.to_au(self.0) / unit.to_au(1.0), unit)
-    }
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Kelvin;
 
-    pub fn value(&self) -> f64 {
-        self.0
-    }
+impl Unit for Kelvin {
+    const TO_AU_MUL: f64 = CODATA.boltzmann / CODATA.hartree;
+    const SYMBOL: &'static str = "K";
+}
 
-    pub fn unit(&self) -> U {
-        self.1
-    }
+#[derive(Debug, Copy, Clone, Default)]
+pub struct MilliKelvin;
+
+impl Unit for MilliKelvin {
+    const TO_AU_MUL: f64 = 1e-3 * Kelvin::TO_AU_MUL;
+    const SYMBOL: &'static str = "mK";
 }
 
-#[derive(Copy, Clone)]
-pub struct Kelvin;
+#[derive(Debug, Copy, Clone, Default)]
+pub struct MicroKelvin;
 
-impl Unit for Kelvin {
-    const TO_AU_MUL: f64 = 3.1668105e-6;
+impl Unit for MicroKelvin {
+    const TO_AU_MUL: f64 = 1e-6 * Kelvin::TO_AU_MUL;
+    const SYMBOL: &'static str = "μK";
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct NanoKelvin;
+
+impl Unit for NanoKelvin {
+    const TO_AU_MUL: f64 = 1e-9 * Kelvin::TO_AU_MUL;
+    const SYMBOL: &'static str = "nK";
 }
 
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone, Default)]
 pub struct CmInv;
 
 impl Unit for CmInv {
-    const TO_AU_MUL: f64 = 4.5563352812e-6;
+    const TO_AU_MUL: f64 = 100.0 * CODATA.planck * CODATA.speed_of_light / CODATA.hartree;
+    const SYMBOL: &'static str = "cm⁻¹";
 }
 
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Hz;
+
+impl Unit for Hz {
+    const TO_AU_MUL: f64 = CODATA.planck / CODATA.hartree;
+    const SYMBOL: &'static str = "Hz";
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct KHz;
+
+impl Unit for KHz {
+    const TO_AU_MUL: f64 = 1e3 * Hz::TO_AU_MUL;
+    const SYMBOL: &'static str = "kHz";
+}
+
+#[derive(Debug, Copy, Clone, Default)]
 pub struct MHz;
 
 impl Unit for MHz {
-    const TO_AU_MUL: f64 = 1.51982850071586e-10;
+    const TO_AU_MUL: f64 = 1e6 * Hz::TO_AU_MUL;
+    const SYMBOL: &'static str = "MHz";
 }
 
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone, Default)]
 pub struct GHz;
 
 impl Unit for GHz {
-    const TO_AU_MUL: f64 = 1.51982850071586e-07;
+    const TO_AU_MUL: f64 = 1e9 * Hz::TO_AU_MUL;
+    const SYMBOL: &'static str = "GHz";
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct THz;
+
+impl Unit for THz {
+    const TO_AU_MUL: f64 = 1e12 * Hz::TO_AU_MUL;
+    const SYMBOL: &'static str = "THz";
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct EV;
+
+impl Unit for EV {
+    const TO_AU_MUL: f64 = CODATA.elementary_charge / CODATA.hartree;
+    const SYMBOL: &'static str = "eV";
+}
+
+/// Millielectronvolt (meV), not to be confused with megaelectronvolt.
+#[doc(alias = "MeV")]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct MilliEV;
+
+impl Unit for MilliEV {
+    const TO_AU_MUL: f64 = 1e-3 * EV::TO_AU_MUL;
+    const SYMBOL: &'static str = "meV";
+}
+
+/// Hartree energy, equal to the atomic unit of energy.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Hartree;
+
+impl Unit for Hartree {
+    const TO_AU_MUL: f64 = 1.0;
+    const SYMBOL: &'static str = "Eh";
 }
 
+#[derive(Debug, Copy, Clone, Default)]
+pub struct KJPerMol;
+
+impl Unit for KJPerMol {
+    const TO_AU_MUL: f64 = 1e3 / (CODATA.avogadro * CODATA.hartree);
+    const SYMBOL: &'static str = "kJ/mol";
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct KcalPerMol;
+
+impl Unit for KcalPerMol {
+    const TO_AU_MUL: f64 = CALORIE * KJPerMol::TO_AU_MUL;
+    const SYMBOL: &'static str = "kcal/mol";
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Joule;
+
+impl Unit for Joule {
+    const TO_AU_MUL: f64 = 1.0 / CODATA.hartree;
+    const SYMBOL: &'static str = "J";
+}
+
+any_unit!(
+    /// Energy unit chosen at runtime.
+    /// # Examples
+    /// ```
+    /// use quantum::units::energy_units::{AnyEnergyUnit, Energy, Kelvin};
+    /// let energy: Energy<AnyEnergyUnit> = "1.5 K".parse().unwrap();
+    /// assert_eq!(energy.unit(), AnyEnergyUnit::Kelvin);
+    /// assert_eq!(energy.to_au(), Energy(1.5, Kelvin).to_au());
+    /// ```
+    AnyEnergyUnit: EnergyDim {
+        Au => ["au", "a.u."],
+        Hartree => ["Eh", "Hartree"],
+        Kelvin => ["K", "Kelvin"],
+        MilliKelvin => ["mK"],
+        MicroKelvin => ["uK", "μK", "µK"],
+        NanoKelvin => ["nK"],
+        CmInv => ["cm-1", "cm^-1", "cm⁻¹", "1/cm"],
+        Hz => ["Hz"],
+        KHz => ["kHz"],
+        MHz => ["MHz"],
+        GHz => ["GHz"],
+        THz => ["THz"],
+        EV => ["eV"],
+        MilliEV => ["meV"],
+        KJPerMol => ["kJ/mol"],
+        KcalPerMol => ["kcal/mol"],
+        Joule => ["J"],
+    }
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::units::ParseQuantityError;
+
     #[test]
     fn energy_units() {
         let energy_kelvin = Energy(1.0, Kelvin);
@@ -69,4 +199,76 @@ mod tests {
         assert!(energy_cm_inv.value() > 0.6950);
         assert!(energy_cm_inv.value() < 0.6951);
     }
+
+    #[test]
+    fn extended_energy_units() {
+        let hartree = Energy(1.0, Hartree);
+        assert!(hartree.approx_eq(Energy(1.0, Au), Energy(0.0, Au)));
+        assert!(hartree
+            .to(EV)
+            .approx_eq_rel(Energy(27.211386245988, EV), 1e-12));
+        assert!(hartree
+            .to(KJPerMol)
+            .approx_eq_rel(Energy(2625.4996394799, KJPerMol), 1e-10));
+        assert!(hartree
+            .to(KcalPerMol)
+            .approx_eq_rel(Energy(627.509474063, KcalPerMol), 1e-10));
+        assert!(hartree
+            .to(Joule)
+            .approx_eq_rel(Energy(4.3597447222071e-18, Joule), 1e-12));
+
+        let kelvin = Energy(1.0, Kelvin);
+        assert!(kelvin
+            .to(GHz)
+            .approx_eq_rel(Energy(20.836619123, GHz), 1e-9));
+        assert!(kelvin
+            .to(NanoKelvin)
+            .approx_eq_rel(Energy(1e9, NanoKelvin), 1e-12));
+        assert!(kelvin
+            .to(MicroKelvin)
+            .approx_eq_rel(Energy(1e6, MicroKelvin), 1e-12));
+        assert!(kelvin
+            .to(MilliKelvin)
+            .approx_eq_rel(Energy(1e3, MilliKelvin), 1e-12));
+
+        let ev = Energy(1.0, EV);
+        assert!(ev.to(CmInv).approx_eq_rel(Energy(8065.543937, CmInv), 1e-9));
+        assert!(ev
+            .to(Kelvin)
+            .approx_eq_rel(Energy(11604.51812, Kelvin), 1e-9));
+        assert!(ev.to(MilliEV).approx_eq_rel(Energy(1e3, MilliEV), 1e-12));
+
+        let thz = Energy(1.0, THz);
+        assert!(thz.to(GHz).approx_eq_rel(Energy(1e3, GHz), 1e-12));
+        assert!(thz.to(MHz).approx_eq_rel(Energy(1e6, MHz), 1e-12));
+        assert!(thz.to(KHz).approx_eq_rel(Energy(1e9, KHz), 1e-12));
+        assert!(thz.to(Hz).approx_eq_rel(Energy(1e12, Hz), 1e-12));
+    }
+
+    #[test]
+    fn energy_parsing() {
+        let energy: Energy<AnyEnergyUnit> = "0.3 cm-1".parse().unwrap();
+        assert_eq!(energy.unit(), AnyEnergyUnit::CmInv);
+        assert_eq!(energy.to_au(), Energy(0.3, CmInv).to_au());
+
+        let energy: Energy<AnyEnergyUnit> = "-1.5e-3K".parse().unwrap();
+        assert_eq!(energy.value(), -1.5e-3);
+        assert_eq!(energy.unit(), AnyEnergyUnit::Kelvin);
+
+        let energy = Energy(2.0, GHz).to(AnyEnergyUnit::MHz);
+        assert!((energy.value() - 2000.0).abs() < 1e-9);
+
+        let error = "1.5 furlong".parse::<Energy<AnyEnergyUnit>>().unwrap_err();
+        assert_eq!(
+            error,
+            ParseQuantityError::UnknownUnit("furlong".to_string())
+        );
+        let energy: Energy<AnyEnergyUnit> = "2 kcal/mol".parse().unwrap();
+        assert_eq!(energy.unit(), AnyEnergyUnit::KcalPerMol);
+
+        let error = "abc K".parse::<Energy<AnyEnergyUnit>>().unwrap_err();
+        assert_eq!(error, ParseQuantityError::InvalidValue("abc".to_string()));
+        let error = "".parse::<Energy<AnyEnergyUnit>>().unwrap_err();
+        assert_eq!(error, ParseQuantityError::Empty);
+    }
 }
@@ -0,0 +1,76 @@
+use super::{
+    dimensions::{FrequencyDim, UnitOf},
+    quantity::Quantity,
+    time_units::AU_TIME,
+    Au, Unit,
+};
+
+/// Quantity representing cyclic frequency values, alias of [`Quantity`] with frequency dimension.
+///
+/// Units in this module measure frequency itself,
+/// whereas [`super::energy_units::MHz`] and similar measure energy h ν.
+/// # Examples
+/// ```
+/// use quantum::units::frequency_units::{Frequency, GigaHertz, MegaHertz};
+/// let frequency = Frequency(1.0, GigaHertz);
+/// assert!(frequency.to(MegaHertz).approx_eq_rel(Frequency(1000.0, MegaHertz), 1e-12));
+/// ```
+pub type Frequency<U, T = f64> = Quantity<FrequencyDim, U, T>;
+
+/// Creates frequency value in given unit.
+#[allow(non_snake_case)]
+pub fn Frequency<U: UnitOf<FrequencyDim>, T>(value: T, unit: U) -> Frequency<U, T> {
+    Quantity::new(value, unit)
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Hertz;
+
+impl Unit for Hertz {
+    const TO_AU_MUL: f64 = AU_TIME;
+    const SYMBOL: &'static str = "Hz";
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct KiloHertz;
+
+impl Unit for KiloHertz {
+    const TO_AU_MUL: f64 = 1e3 * Hertz::TO_AU_MUL;
+    const SYMBOL: &'static str = "kHz";
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct MegaHertz;
+
+impl Unit for MegaHertz {
+    const TO_AU_MUL: f64 = 1e6 * Hertz::TO_AU_MUL;
+    const SYMBOL: &'static str = "MHz";
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct GigaHertz;
+
+impl Unit for GigaHertz {
+    const TO_AU_MUL: f64 = 1e9 * Hertz::TO_AU_MUL;
+    const SYMBOL: &'static str = "GHz";
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct TeraHertz;
+
+impl Unit for TeraHertz {
+    const TO_AU_MUL: f64 = 1e12 * Hertz::TO_AU_MUL;
+    const SYMBOL: &'static str = "THz";
+}
+
+any_unit!(
+    /// Frequency unit chosen at runtime.
+    AnyFrequencyUnit: FrequencyDim {
+        Au => ["au", "a.u."],
+        Hertz => ["Hz"],
+        KiloHertz => ["kHz"],
+        MegaHertz => ["MHz"],
+        GigaHertz => ["GHz"],
+        TeraHertz => ["THz"],
+    }
+);
@@ -0,0 +1,167 @@
+use std::marker::PhantomData;
+
+use crate::utility::{geomspace, linspace, logspace};
+
+use super::{dimensions::UnitOf, quantity::Quantity, DynUnit};
+
+/// Grid of values of dimension `D` sharing a single unit `U`.
+/// # Examples
+/// ```
+/// use quantum::units::{energy_units::{Energy, Kelvin, NanoKelvin}, grid::Grid};
+/// let energies = Grid::logarithmic(Energy(1.0, NanoKelvin), Energy(1.0, Kelvin), 10);
+/// assert_eq!(energies.len(), 10);
+/// assert!(energies.get(9).unwrap().approx_eq_rel(Energy(1.0, Kelvin), 1e-12));
+///
+/// for energy in energies.iter() {
+///     let _energy_au = energy.to_au();
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Grid<D, U: DynUnit> {
+    values: Vec<f64>,
+    unit: U,
+    dim: PhantomData<D>,
+}
+
+impl<D, U: UnitOf<D>> Grid<D, U> {
+    /// Creates grid from the values given in `unit`.
+    pub fn new(values: Vec<f64>, unit: U) -> Self {
+        Self {
+            values,
+            unit,
+            dim: PhantomData,
+        }
+    }
+
+    /// Linearly spaced grid between `start` and `end` inclusive in the unit of `start`.
+    pub fn linear<V: DynUnit>(start: Quantity<D, U>, end: Quantity<D, V>, n: usize) -> Self {
+        let end = end_value(&start, &end);
+
+        Self::with_end(linspace(start.value(), end, n), end, start.unit())
+    }
+
+    /// Logarithmically spaced grid between `start` and `end` inclusive in the unit of `start`.
+    /// # Panics
+    /// Panics if `start` or `end` is zero or they are of opposite sign, see [`logspace`].
+    pub fn logarithmic<V: DynUnit>(start: Quantity<D, U>, end: Quantity<D, V>, n: usize) -> Self {
+        let end = end_value(&start, &end);
+
+        Self::with_end(logspace(start.value(), end, n), end, start.unit())
+    }
+
+    /// Creates grid with the last value set exactly to `end`.
+    fn with_end(mut values: Vec<f64>, end: f64, unit: U) -> Self {
+        if values.len() > 1 {
+            if let Some(last) = values.last_mut() {
+                *last = end;
+            }
+        }
+
+        Self::new(values, unit)
+    }
+
+    /// Grid of `n` values starting at `start` with consecutive values differing by `ratio`.
+    pub fn geometric(start: Quantity<D, U>, ratio: f64, n: usize) -> Self {
+        Self::new(geomspace(start.value(), ratio, n), start.unit())
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn unit(&self) -> U {
+        self.unit
+    }
+
+    /// Values of the grid in its unit.
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    pub fn get(&self, index: usize) -> Option<Quantity<D, U>> {
+        self.values
+            .get(index)
+            .map(|&value| Quantity::new(value, self.unit))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Quantity<D, U>> + '_ {
+        self.values
+            .iter()
+            .map(|&value| Quantity::new(value, self.unit))
+    }
+
+    /// Converts the grid to a different unit.
+    pub fn to<V: UnitOf<D>>(&self, unit: V) -> Grid<D, V> {
+        let mul = self.unit.to_au(1.0) / unit.to_au(1.0);
+
+        Grid::new(self.values.iter().map(|x| x * mul).collect(), unit)
+    }
+
+    /// Values of the grid in atomic units.
+    pub fn to_au(&self) -> Vec<f64> {
+        self.values.iter().map(|&x| self.unit.to_au(x)).collect()
+    }
+}
+
+/// Value of `end` in the unit of `start`, unchanged if both units have the same multiplier.
+fn end_value<D, U: UnitOf<D>, V: DynUnit>(start: &Quantity<D, U>, end: &Quantity<D, V>) -> f64 {
+    if start.unit().to_au(1.0) == end.unit().to_au(1.0) {
+        end.value()
+    } else {
+        end.to(start.unit()).value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::units::{
+        distance_units::{Angstrom, Distance},
+        energy_units::{AnyEnergyUnit, Energy, GHz, MHz},
+        Au,
+    };
+
+    use crate::utility::logspace;
+
+    use super::Grid;
+
+    #[test]
+    fn grids() {
+        let grid = Grid::linear(Distance(1.0, Angstrom), Distance(10.0, Angstrom), 10);
+        assert_eq!(grid.values(), &[1., 2., 3., 4., 5., 6., 7., 8., 9., 10.]);
+        assert!(grid
+            .to(Au)
+            .get(0)
+            .unwrap()
+            .approx_eq_rel(Distance(1.0, Angstrom), 1e-12));
+
+        let grid = Grid::logarithmic(Energy(1.0, MHz), Energy(1.0, GHz), 4);
+        for (energy, expected) in grid.iter().zip([1.0, 10.0, 100.0, 1000.0]) {
+            assert!(energy.approx_eq_rel(Energy(expected, MHz), 1e-12));
+        }
+
+        let grid = Grid::geometric(Energy(-1.0, MHz), 2.0, 4);
+        assert_eq!(grid.values(), &[-1.0, -2.0, -4.0, -8.0]);
+        assert!((grid.to_au()[3] / Energy(-8.0, MHz).to_au() - 1.0).abs() < 1e-12);
+
+        let grid = Grid::linear(Energy(1.0, MHz), Energy(2.0, AnyEnergyUnit::MHz), 3);
+        assert_eq!(grid.values(), &[1.0, 1.5, 2.0]);
+        let grid = Grid::logarithmic(Energy(1.0, MHz), Energy(3.0, GHz), 7);
+        assert!((grid.values()[6] - 3000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "nonzero and of the same sign")]
+    fn logarithmic_opposite_signs() {
+        Grid::logarithmic(Energy(-1.0, MHz), Energy(1.0, GHz), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "nonzero and of the same sign")]
+    fn logspace_zero() {
+        logspace(0.0, 1.0, 4);
+    }
+}
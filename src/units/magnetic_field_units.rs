@@ -0,0 +1,100 @@
+use std::f64::consts::TAU;
+
+use super::{
+    constants::CODATA,
+    dimensions::{MagneticFieldDim, MagneticMomentDim, UnitOf},
+    energy_units::Energy,
+    quantity::Quantity,
+    Au, DynUnit, Unit,
+};
+
+/// Atomic unit of magnetic field ħ / (e a0^2) in T.
+const AU_FIELD: f64 =
+    CODATA.planck / TAU / (CODATA.elementary_charge * CODATA.bohr_radius * CODATA.bohr_radius);
+/// Atomic unit of magnetic moment e ħ / m_e in J/T.
+const AU_MOMENT: f64 = CODATA.elementary_charge * CODATA.planck / TAU / CODATA.electron_mass;
+
+/// Quantity representing magnetic field values, alias of [`Quantity`] with magnetic field dimension.
+/// # Examples
+/// ```
+/// use quantum::units::{
+///     energy_units::{Energy, Kelvin},
+///     magnetic_field_units::{BohrMagneton, Gauss, MagneticField, MagneticMoment},
+/// };
+/// let field = MagneticField(1000.0, Gauss);
+/// let energy = field.coupling_energy(MagneticMoment(1.0, BohrMagneton));
+/// assert!(energy.approx_eq(Energy(0.0672, Kelvin), Energy(1e-4, Kelvin)));
+/// ```
+pub type MagneticField<U, T = f64> = Quantity<MagneticFieldDim, U, T>;
+
+/// Creates magnetic field value in given unit.
+#[allow(non_snake_case)]
+pub fn MagneticField<U: UnitOf<MagneticFieldDim>, T>(value: T, unit: U) -> MagneticField<U, T> {
+    Quantity::new(value, unit)
+}
+
+impl<U: DynUnit> MagneticField<U> {
+    /// Returns the energy μ B of the magnetic `moment` in this field.
+    pub fn coupling_energy<V: DynUnit>(&self, moment: MagneticMoment<V>) -> Energy<Au> {
+        *self * moment
+    }
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Tesla;
+
+impl Unit for Tesla {
+    const TO_AU_MUL: f64 = 1.0 / AU_FIELD;
+    const SYMBOL: &'static str = "T";
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Gauss;
+
+impl Unit for Gauss {
+    const TO_AU_MUL: f64 = 1e-4 * Tesla::TO_AU_MUL;
+    const SYMBOL: &'static str = "G";
+}
+
+/// Quantity representing magnetic moment values, alias of [`Quantity`] with magnetic moment dimension.
+pub type MagneticMoment<U, T = f64> = Quantity<MagneticMomentDim, U, T>;
+
+/// Creates magnetic moment value in given unit.
+#[allow(non_snake_case)]
+pub fn MagneticMoment<U: UnitOf<MagneticMomentDim>, T>(value: T, unit: U) -> MagneticMoment<U, T> {
+    Quantity::new(value, unit)
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct BohrMagneton;
+
+impl Unit for BohrMagneton {
+    const TO_AU_MUL: f64 = CODATA.bohr_magneton / AU_MOMENT;
+    const SYMBOL: &'static str = "μB";
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct NuclearMagneton;
+
+impl Unit for NuclearMagneton {
+    const TO_AU_MUL: f64 = CODATA.nuclear_magneton / AU_MOMENT;
+    const SYMBOL: &'static str = "μN";
+}
+
+any_unit!(
+    /// Magnetic field unit chosen at runtime.
+    AnyMagneticFieldUnit: MagneticFieldDim {
+        Au => ["au", "a.u."],
+        Tesla => ["T"],
+        Gauss => ["G"],
+    }
+);
+
+any_unit!(
+    /// Magnetic moment unit chosen at runtime.
+    AnyMagneticMomentUnit: MagneticMomentDim {
+        Au => ["au", "a.u."],
+        BohrMagneton => ["μB", "muB"],
+        NuclearMagneton => ["μN", "muN"],
+    }
+);
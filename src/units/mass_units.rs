@@ -1,6 +1,11 @@
-use super::Unit;
+use super::{
+    constants::CODATA,
+    dimensions::{MassDim, UnitOf},
+    quantity::Quantity,
+    Au, Unit,
+};
 
-/// Struct for representing mass unit values
+/// Quantity representing mass values, alias of [`Quantity`] with mass dimension.
 /// # Examples
 /// ```
 /// use quantum::units::{Au, mass_units::{Mass, Dalton}};
@@ -8,30 +13,59 @@ use super::Unit;
 /// let mass_au = mass_dalton.to(Au);
 /// let mass = mass_dalton.to_au();
 /// assert_eq!(mass, mass_au.value())
-#[derive(Debug, Copy, Clone)]
-pub struct Mass<U: Unit>(pub f64, pub U);
+/// ```
+/// Conversions between SI and atomic units
+/// ```
+/// use quantum::units::{constants::CODATA, mass_units::{Dalton, ElectronMass, Kilogram, Mass}};
+/// let mass = Mass(1.0, Dalton);
+/// assert!((mass.to(ElectronMass).value() - 1822.888486).abs() < 1e-6);
+/// assert!(mass.to(Kilogram).approx_eq_rel(Mass(CODATA.atomic_mass_unit, Kilogram), 1e-12));
+/// ```
+pub type Mass<U, T = f64> = Quantity<MassDim, U, T>;
 
-impl<U: Unit> Mass<U> {
-    pub fn to_au(&self) -> f64 {
-        self.1.to_au(self.0)
-    }
+/// Creates mass value in given unit.
+#[allow(non_snake_case)]
+pub fn Mass<U: UnitOf<MassDim>, T>(value: T, unit: U) -> Mass<U, T> {
+    Quantity::new(value, unit)
+}
 
-    pub fn to<V: Unit>(&self, unit: V) -> Mass<V> {
-        Mass(self.1.to_au(self.0) / unit.to_au(1.0), unit)
-    }
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Dalton;
 
-    pub fn value(&self) -> f64 {
-        self.0
-    }
+impl Unit for Dalton {
+    const TO_AU_MUL: f64 = CODATA.atomic_mass_unit / CODATA.electron_mass;
+    const SYMBOL: &'static str = "u";
+}
 
-    pub fn unit(&self) -> U {
-        self.1
-    }
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ElectronMass;
+
+impl Unit for ElectronMass {
+    const TO_AU_MUL: f64 = 1.0;
+    const SYMBOL: &'static str = "mₑ";
 }
 
-#[derive(Copy, Clone)]
-pub struct Dalton;
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Kilogram;
 
-impl Unit for Dalton {
-    const TO_AU_MUL: f64 = 1822.88839;
+impl Unit for Kilogram {
+    const TO_AU_MUL: f64 = 1.0 / CODATA.electron_mass;
+    const SYMBOL: &'static str = "kg";
 }
+
+any_unit!(
+    /// Mass unit chosen at runtime.
+    /// # Examples
+    /// ```
+    /// use quantum::units::mass_units::{AnyMassUnit, Mass, Dalton};
+    /// let mass: Mass<AnyMassUnit> = "86.909 u".parse().unwrap();
+    /// assert_eq!(mass.unit(), AnyMassUnit::Dalton);
+    /// assert_eq!(mass.to_au(), Mass(86.909, Dalton).to_au());
+    /// ```
+    AnyMassUnit: MassDim {
+        Au => ["au", "a.u."],
+        ElectronMass => ["me", "mₑ"],
+        Dalton => ["u", "Da", "amu", "dalton"],
+        Kilogram => ["kg"],
+    }
+);
@@ -0,0 +1,410 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display, LowerExp},
+    iter::Sum,
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+#[cfg(feature = "serde")]
+use serde::{
+    de::Error as _, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer,
+};
+use typenum::{Prod, Quot};
+
+use super::{
+    dimensions::UnitOf, pad_with_symbol, split_value_unit, Au, DynUnit, ParseQuantityError,
+};
+
+/// Value of type `T` of physical dimension `D` expressed in unit `U`.
+/// Specific quantities such as [`super::energy_units::Energy`] are aliases of this type.
+///
+/// Multiplication and division of quantities are done in atomic units
+/// and yield a quantity of resulting dimension.
+/// # Examples
+/// ```
+/// use quantum::units::{
+///     dimensions::MomentOfInertiaDim, distance_units::{Angstrom, Distance},
+///     mass_units::{Dalton, Mass}, quantity::Quantity, Au,
+/// };
+/// let mass = Mass(7.0, Dalton);
+/// let distance = Distance(3.0, Angstrom);
+///
+/// let inertia: Quantity<MomentOfInertiaDim, Au> = mass * distance * distance;
+/// assert_eq!(inertia.value(), mass.to_au() * distance.to_au() * distance.to_au());
+/// ```
+///
+/// Energy divided by frequency is an action, and an action divided by energy is a time,
+/// e.g. the time scale `ħ / E` of a level splitting.
+/// ```
+/// use quantum::units::{
+///     dimensions::ActionDim, energy_units::{Energy, MHz}, quantity::Quantity,
+///     time_units::{MicroSecond, Time}, Au,
+/// };
+/// let hbar = Quantity::<ActionDim, Au>::new(1.0, Au);
+/// let time: Time<Au> = hbar / Energy(1.0, MHz);
+/// assert!(time.approx_eq_rel(Time(1.0 / std::f64::consts::TAU, MicroSecond), 1e-12));
+/// ```
+///
+/// Addition, subtraction and comparison convert the right hand side to the unit of the left hand side.
+/// ```
+/// use quantum::units::energy_units::{Energy, GHz, MHz};
+/// let energy = Energy(1.0, GHz) + Energy(500.0, MHz);
+/// assert_eq!(energy.value(), 1.5);
+/// assert!(Energy(1.0, GHz) > Energy(999.0, MHz));
+/// assert!(energy.approx_eq(Energy(1500.5, MHz), Energy(1.0, MHz)));
+/// ```
+/// Adding quantities of different dimensions does not compile
+/// ```compile_fail
+/// use quantum::units::{distance_units::{Angstrom, Distance}, energy_units::{Energy, Kelvin}};
+/// let _ = Energy(1.0, Kelvin) + Distance(1.0, Angstrom);
+/// ```
+///
+/// Quantities are displayed with the unit symbol, by default with 3 decimal places.
+/// ```
+/// use quantum::units::energy_units::{CmInv, Energy, Kelvin};
+/// let energy = Energy(1.0, Kelvin);
+/// assert_eq!(format!("{energy}"), "1.000 K");
+/// assert_eq!(format!("{:.2}", energy.to(CmInv)), "0.70 cm⁻¹");
+/// assert_eq!(format!("{:.3e}", energy.to(CmInv)), "6.950e-1 cm⁻¹");
+/// assert_eq!(format!("[{:>9.1}]", energy), "[    1.0 K]");
+/// assert_eq!(format!("[{:-<9.1}]", energy), "[1.0 K----]");
+/// ```
+#[derive(Copy, Clone)]
+pub struct Quantity<D, U: DynUnit, T = f64>(pub T, pub U, PhantomData<D>);
+
+/// Numerical type that can be used as a value of a [`Quantity`], such as `f64` or [`super::uncertain::Uncertain`].
+pub trait QuantityValue:
+    Copy
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Neg<Output = Self>
+    + Mul<f64, Output = Self>
+    + Div<f64, Output = Self>
+{
+}
+
+impl<T> QuantityValue for T where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Neg<Output = T>
+        + Mul<f64, Output = T>
+        + Div<f64, Output = T>
+{
+}
+
+impl<D, U: UnitOf<D>, T> Quantity<D, U, T> {
+    pub fn new(value: T, unit: U) -> Self {
+        Quantity(value, unit, PhantomData)
+    }
+}
+
+impl<D, U: DynUnit, T> Quantity<D, U, T> {
+    pub fn unit(&self) -> U {
+        self.1
+    }
+}
+
+impl<D, U: DynUnit, T: QuantityValue> Quantity<D, U, T> {
+    pub fn to_au(&self) -> T {
+        self.0 * self.1.to_au(1.0)
+    }
+
+    pub fn to<V: UnitOf<D>>(&self, unit: V) -> Quantity<D, V, T> {
+        Quantity(self.value_in(unit), unit, PhantomData)
+    }
+
+    /// Value converted to `unit`, both units are known to measure dimension `D`.
+    fn value_in<V: DynUnit>(&self, unit: V) -> T {
+        self.0 * self.1.to_au(1.0) / unit.to_au(1.0)
+    }
+
+    pub fn value(&self) -> T {
+        self.0
+    }
+}
+
+impl<D, U: DynUnit> Quantity<D, U> {
+    pub fn abs(&self) -> Self {
+        Quantity(self.0.abs(), self.1, PhantomData)
+    }
+
+    /// Checks whether the quantities differ at most by `tolerance` given in any unit.
+    pub fn approx_eq<V: DynUnit, W: DynUnit>(
+        &self,
+        other: Quantity<D, V>,
+        tolerance: Quantity<D, W>,
+    ) -> bool {
+        (self.to_au() - other.to_au()).abs() <= tolerance.to_au().abs()
+    }
+
+    /// Checks whether the quantities differ at most by `rel_tolerance` relative to the larger magnitude.
+    pub fn approx_eq_rel<V: DynUnit>(&self, other: Quantity<D, V>, rel_tolerance: f64) -> bool {
+        let (value, other) = (self.to_au(), other.to_au());
+
+        (value - other).abs() <= rel_tolerance * value.abs().max(other.abs())
+    }
+}
+
+impl<D, U: DynUnit + Debug, T: Debug> Debug for Quantity<D, U, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Quantity")
+            .field(&self.0)
+            .field(&self.1)
+            .finish()
+    }
+}
+
+impl<D, U: DynUnit, T: Display> Display for Quantity<D, U, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(3);
+        let value = format!("{:.*}", precision, self.0);
+
+        pad_with_symbol(f, &value, self.1.symbol())
+    }
+}
+
+impl<D, U: DynUnit, T: LowerExp> LowerExp for Quantity<D, U, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(3);
+        let value = format!("{:.*e}", precision, self.0);
+
+        pad_with_symbol(f, &value, self.1.symbol())
+    }
+}
+
+impl<D, U: DynUnit, V: DynUnit, T: QuantityValue + PartialEq> PartialEq<Quantity<D, V, T>>
+    for Quantity<D, U, T>
+{
+    fn eq(&self, other: &Quantity<D, V, T>) -> bool {
+        self.to_au() == other.to_au()
+    }
+}
+
+impl<D, U: DynUnit, V: DynUnit> PartialOrd<Quantity<D, V>> for Quantity<D, U> {
+    fn partial_cmp(&self, other: &Quantity<D, V>) -> Option<Ordering> {
+        self.to_au().partial_cmp(&other.to_au())
+    }
+}
+
+impl<D, U: DynUnit, V: DynUnit, T: QuantityValue> Add<Quantity<D, V, T>> for Quantity<D, U, T> {
+    type Output = Quantity<D, U, T>;
+
+    fn add(self, rhs: Quantity<D, V, T>) -> Self::Output {
+        Quantity(self.0 + rhs.value_in(self.1), self.1, PhantomData)
+    }
+}
+
+impl<D, U: DynUnit, V: DynUnit, T: QuantityValue> Sub<Quantity<D, V, T>> for Quantity<D, U, T> {
+    type Output = Quantity<D, U, T>;
+
+    fn sub(self, rhs: Quantity<D, V, T>) -> Self::Output {
+        Quantity(self.0 - rhs.value_in(self.1), self.1, PhantomData)
+    }
+}
+
+impl<D, U: DynUnit, V: DynUnit, T: QuantityValue> AddAssign<Quantity<D, V, T>>
+    for Quantity<D, U, T>
+{
+    fn add_assign(&mut self, rhs: Quantity<D, V, T>) {
+        self.0 = self.0 + rhs.value_in(self.1)
+    }
+}
+
+impl<D, U: DynUnit, V: DynUnit, T: QuantityValue> SubAssign<Quantity<D, V, T>>
+    for Quantity<D, U, T>
+{
+    fn sub_assign(&mut self, rhs: Quantity<D, V, T>) {
+        self.0 = self.0 - rhs.value_in(self.1)
+    }
+}
+
+impl<D, U: DynUnit, T: QuantityValue> Neg for Quantity<D, U, T> {
+    type Output = Quantity<D, U, T>;
+
+    fn neg(self) -> Self::Output {
+        Quantity(-self.0, self.1, PhantomData)
+    }
+}
+
+impl<D, U: DynUnit, T: QuantityValue> Mul<f64> for Quantity<D, U, T> {
+    type Output = Quantity<D, U, T>;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Quantity(self.0 * rhs, self.1, PhantomData)
+    }
+}
+
+impl<D, U: DynUnit, T: QuantityValue> Mul<Quantity<D, U, T>> for f64 {
+    type Output = Quantity<D, U, T>;
+
+    fn mul(self, rhs: Quantity<D, U, T>) -> Self::Output {
+        Quantity(rhs.0 * self, rhs.1, PhantomData)
+    }
+}
+
+impl<D, U: DynUnit, T: QuantityValue> MulAssign<f64> for Quantity<D, U, T> {
+    fn mul_assign(&mut self, rhs: f64) {
+        self.0 = self.0 * rhs
+    }
+}
+
+impl<D, U: DynUnit, T: QuantityValue> Div<f64> for Quantity<D, U, T> {
+    type Output = Quantity<D, U, T>;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Quantity(self.0 / rhs, self.1, PhantomData)
+    }
+}
+
+impl<D, U: DynUnit, T: QuantityValue> DivAssign<f64> for Quantity<D, U, T> {
+    fn div_assign(&mut self, rhs: f64) {
+        self.0 = self.0 / rhs
+    }
+}
+
+/// Sum of quantities in unit `U`, empty sum gives zero in the default unit.
+impl<D, U: DynUnit + Default, V: DynUnit, T: QuantityValue + Default> Sum<Quantity<D, V, T>>
+    for Quantity<D, U, T>
+{
+    fn sum<I: Iterator<Item = Quantity<D, V, T>>>(iter: I) -> Self {
+        iter.fold(
+            Quantity(T::default(), U::default(), PhantomData),
+            |acc, x| acc + x,
+        )
+    }
+}
+
+impl<D1: Mul<D2>, D2, U: DynUnit, V: DynUnit, T: QuantityValue + Mul<Output = T>>
+    Mul<Quantity<D2, V, T>> for Quantity<D1, U, T>
+{
+    type Output = Quantity<Prod<D1, D2>, Au, T>;
+
+    fn mul(self, rhs: Quantity<D2, V, T>) -> Self::Output {
+        Quantity(self.to_au() * rhs.to_au(), Au, PhantomData)
+    }
+}
+
+impl<D1: Div<D2>, D2, U: DynUnit, V: DynUnit, T: QuantityValue + Div<Output = T>>
+    Div<Quantity<D2, V, T>> for Quantity<D1, U, T>
+{
+    type Output = Quantity<Quot<D1, D2>, Au, T>;
+
+    fn div(self, rhs: Quantity<D2, V, T>) -> Self::Output {
+        Quantity(self.to_au() / rhs.to_au(), Au, PhantomData)
+    }
+}
+
+impl<D, U: UnitOf<D> + FromStr<Err = ParseQuantityError>> FromStr for Quantity<D, U> {
+    type Err = ParseQuantityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value, unit) = split_value_unit(s)?;
+
+        Ok(Quantity::new(value, unit.parse()?))
+    }
+}
+
+/// Serializes as the value together with the unit symbol tag, e.g. `{"value": 1.5, "unit": "K"}`.
+#[cfg(feature = "serde")]
+impl<D, U: DynUnit, T: Serialize> Serialize for Quantity<D, U, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Quantity", 2)?;
+        state.serialize_field("value", &self.0)?;
+        state.serialize_field("unit", self.1.symbol())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, D, U: UnitOf<D> + Default, T: Deserialize<'de>> Deserialize<'de> for Quantity<D, U, T> {
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        #[derive(Deserialize)]
+        struct Tagged<T> {
+            value: T,
+            unit: String,
+        }
+
+        let tagged = Tagged::<T>::deserialize(deserializer)?;
+        let unit = U::from_symbol(&tagged.unit)
+            .ok_or_else(|| De::Error::custom(ParseQuantityError::UnknownUnit(tagged.unit)))?;
+
+        Ok(Quantity::new(tagged.value, unit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::TAU;
+
+    use crate::units::{
+        dimensions::{ActionDim, EnergyDim, UnitOf},
+        distance_units::{Angstrom, Distance},
+        energy_units::{CmInv, Energy, GHz, Kelvin, MHz},
+        frequency_units::{Frequency, GigaHertz},
+        time_units::{NanoSecond, Time},
+        Au, Unit,
+    };
+
+    use super::Quantity;
+
+    #[test]
+    fn arithmetic() {
+        let mut energy = Energy(1.0, Kelvin);
+        energy += Energy(1.0, CmInv);
+        assert!(energy.approx_eq(Energy(2.4388, Kelvin), Energy(1e-4, Kelvin)));
+        energy -= Energy(1.0, CmInv);
+        assert!(energy.approx_eq_rel(Energy(1.0, Kelvin), 1e-12));
+
+        let energy = -(2.0 * Energy(3.0, GHz) / 4.0);
+        assert_eq!(energy.value(), -1.5);
+        assert!(energy.abs().approx_eq_rel(Energy(1500.0, MHz), 1e-12));
+
+        let energies = [Energy(1.0, GHz), Energy(2.0, GHz), Energy(1.0, GHz)];
+        let total: Energy<Au> = energies.into_iter().sum();
+        assert!(total.approx_eq(Energy(4.0, GHz), Energy(1e-9, MHz)));
+
+        assert!(Distance(1.0, Angstrom) > Distance(1.0, Au));
+        assert!(Energy(1.0, MHz) < Energy(1.0, GHz));
+    }
+
+    #[test]
+    fn display_without_symbol() {
+        #[derive(Clone, Copy)]
+        struct HalfHartree;
+
+        impl Unit for HalfHartree {
+            const TO_AU_MUL: f64 = 0.5;
+        }
+
+        impl UnitOf<EnergyDim> for HalfHartree {}
+
+        let energy = Energy(1.0, Au).to(HalfHartree);
+        assert_eq!(format!("{energy:.1}"), "2.0");
+        assert_eq!(format!("{energy:>7.1e}"), "  2.0e0");
+        assert_eq!(format!("{:.1}", Energy(1.0, Au)), "1.0 a.u.");
+    }
+
+    #[test]
+    fn display_flags() {
+        assert_eq!(format!("{:+.2}", Energy(1.0, Kelvin)), "+1.00 K");
+        assert_eq!(format!("{:+.2}", Energy(-1.0, Kelvin)), "-1.00 K");
+        assert_eq!(format!("{:08.2}", Energy(1.0, Kelvin)), "001.00 K");
+        assert_eq!(format!("{:+09.1}", Energy(-1.0, Kelvin)), "-0001.0 K");
+        assert_eq!(format!("{:+010.1e}", Energy(1.0, Kelvin)), "+001.0e0 K");
+    }
+
+    #[test]
+    fn derived_dimensions() {
+        let planck: Quantity<ActionDim, Au> = Energy(1.0, GHz) / Frequency(1.0, GigaHertz);
+        assert!((planck.value() - TAU).abs() < 1e-12);
+
+        let period: Time<Au> = planck / Energy(1.0, GHz);
+        assert!(period.approx_eq_rel(Time(1.0, NanoSecond), 1e-12));
+
+        let frequency: Frequency<Au> = Energy(1.0, GHz) / planck;
+        assert!(frequency.approx_eq_rel(Frequency(1.0, GigaHertz), 1e-12));
+    }
+}
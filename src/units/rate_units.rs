@@ -0,0 +1,51 @@
+use std::f64::consts::TAU;
+
+use super::{
+    constants::CODATA,
+    dimensions::{RateCoefficientDim, UnitOf},
+    quantity::Quantity,
+    Au, Unit,
+};
+
+/// Atomic unit of rate coefficient a0^3 E_h / ħ in m^3/s.
+const AU_RATE: f64 = CODATA.bohr_radius * CODATA.bohr_radius * CODATA.bohr_radius * CODATA.hartree
+    / (CODATA.planck / TAU);
+
+/// Quantity representing two-body rate coefficient values, alias of [`Quantity`] with volume per time dimension.
+/// # Examples
+/// ```
+/// use quantum::units::{
+///     Au, cross_section_units::{CrossSection, CmSquared},
+///     distance_units::{Distance, Angstrom}, time_units::{Time, PicoSecond},
+///     rate_units::{RateCoefficient, CmCubedPerSecond},
+/// };
+/// let velocity = Distance(1.0, Angstrom) / Time(1.0, PicoSecond);
+/// let rate: RateCoefficient<Au> = CrossSection(1e-14, CmSquared) * velocity;
+/// assert!(rate.to(CmCubedPerSecond).approx_eq_rel(RateCoefficient(1e-10, CmCubedPerSecond), 1e-12));
+/// ```
+pub type RateCoefficient<U, T = f64> = Quantity<RateCoefficientDim, U, T>;
+
+/// Creates rate coefficient value in given unit.
+#[allow(non_snake_case)]
+pub fn RateCoefficient<U: UnitOf<RateCoefficientDim>, T>(
+    value: T,
+    unit: U,
+) -> RateCoefficient<U, T> {
+    Quantity::new(value, unit)
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct CmCubedPerSecond;
+
+impl Unit for CmCubedPerSecond {
+    const TO_AU_MUL: f64 = 1e-6 / AU_RATE;
+    const SYMBOL: &'static str = "cm³/s";
+}
+
+any_unit!(
+    /// Rate coefficient unit chosen at runtime.
+    AnyRateCoefficientUnit: RateCoefficientDim {
+        Au => ["au", "a.u."],
+        CmCubedPerSecond => ["cm^3/s", "cm3/s", "cm³/s"],
+    }
+);
@@ -0,0 +1,93 @@
+use std::fmt::Display;
+
+use super::{
+    energy_units::{GHz, Hz, KHz, Kelvin, MHz, MicroKelvin, MilliKelvin, NanoKelvin, THz},
+    frequency_units::{GigaHertz, Hertz, KiloHertz, MegaHertz, TeraHertz},
+    pad_with_symbol,
+    quantity::Quantity,
+    Unit,
+};
+
+const PREFIXES: [(i32, &str); 10] = [
+    (-15, "f"),
+    (-12, "p"),
+    (-9, "n"),
+    (-6, "μ"),
+    (-3, "m"),
+    (0, ""),
+    (3, "k"),
+    (6, "M"),
+    (9, "G"),
+    (12, "T"),
+];
+
+/// Unit that is an SI prefixed variant of a base unit, such as `mK` of `K`.
+pub trait SiPrefixedUnit: Unit {
+    /// Symbol of the base unit.
+    const BASE_SYMBOL: &'static str;
+    /// Value of the unit in base units.
+    const BASE_MUL: f64;
+}
+
+impl<D, U: SiPrefixedUnit> Quantity<D, U> {
+    /// Displays the quantity with SI prefix chosen such that the value lies in [1, 1000).
+    /// # Examples
+    /// ```
+    /// use quantum::units::energy_units::{Energy, Kelvin, MHz};
+    /// assert_eq!(format!("{}", Energy(2.5e-7, Kelvin).with_si_prefix()), "250.000 nK");
+    /// assert_eq!(format!("{:.1}", Energy(1500.0, MHz).with_si_prefix()), "1.5 GHz");
+    /// assert_eq!(format!("{:>8.1}", Energy(1500.0, MHz).with_si_prefix()), " 1.5 GHz");
+    /// ```
+    pub fn with_si_prefix(&self) -> SiPrefixed {
+        let value = self.0 * U::BASE_MUL;
+        let exponent = if value == 0.0 || !value.is_finite() {
+            0
+        } else {
+            3 * (value.abs().log10() / 3.0).floor() as i32
+        };
+
+        let (exponent, prefix) = PREFIXES
+            .iter()
+            .rev()
+            .find(|(e, _)| *e <= exponent)
+            .unwrap_or(&PREFIXES[0]);
+
+        SiPrefixed {
+            value: value / 10f64.powi(*exponent),
+            prefix,
+            base_symbol: U::BASE_SYMBOL,
+        }
+    }
+}
+
+/// Quantity formatted with automatically chosen SI prefix.
+#[derive(Debug, Clone, Copy)]
+pub struct SiPrefixed {
+    value: f64,
+    prefix: &'static str,
+    base_symbol: &'static str,
+}
+
+impl Display for SiPrefixed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(3);
+        let value = format!("{:.*}", precision, self.value);
+
+        pad_with_symbol(f, &value, &format!("{}{}", self.prefix, self.base_symbol))
+    }
+}
+
+macro_rules! impl_si_prefixed {
+    ($base:literal: $($unit:ty => $mul:expr),+) => {
+        $(
+            impl SiPrefixedUnit for $unit {
+                const BASE_SYMBOL: &'static str = $base;
+                const BASE_MUL: f64 = $mul;
+            }
+        )+
+    };
+}
+
+impl_si_prefixed!("K": Kelvin => 1.0, MilliKelvin => 1e-3, MicroKelvin => 1e-6, NanoKelvin => 1e-9);
+impl_si_prefixed!("Hz": Hz => 1.0, KHz => 1e3, MHz => 1e6, GHz => 1e9, THz => 1e12);
+impl_si_prefixed!("Hz": Hertz => 1.0, KiloHertz => 1e3, MegaHertz => 1e6, GigaHertz => 1e9, TeraHertz => 1e12);
@@ -0,0 +1,124 @@
+use std::f64::consts::TAU;
+
+use super::{
+    constants::CODATA,
+    dimensions::{LengthDim, UnitOf},
+    distance_units::{Distance, Micrometer},
+    energy_units::Energy,
+    frequency_units::Frequency,
+    time_units::AU_TIME,
+    Au, DynUnit,
+};
+
+/// Speed of light in atomic units.
+const SPEED_OF_LIGHT_AU: f64 = CODATA.speed_of_light * AU_TIME / CODATA.bohr_radius;
+
+/// Wavelength of the light, alias of [`Distance`].
+pub type Wavelength<U, T = f64> = Distance<U, T>;
+
+/// Creates wavelength value in given unit.
+#[allow(non_snake_case)]
+pub fn Wavelength<U: UnitOf<LengthDim>, T>(value: T, unit: U) -> Wavelength<U, T> {
+    Distance(value, unit)
+}
+
+/// Medium in which the wavelength is measured.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Medium {
+    Vacuum,
+    /// Standard dry air at 15 °C and 101.325 kPa.
+    Air,
+}
+
+impl Medium {
+    /// Refractive index of the medium for light of given vacuum wavelength.
+    /// For air uses the Edlén formula in the form given by Morton (2000),
+    /// which is valid for wavelengths above 200 nm.
+    pub fn refractive_index<U: DynUnit>(&self, vacuum_wavelength: Wavelength<U>) -> f64 {
+        match self {
+            Medium::Vacuum => 1.0,
+            Medium::Air => {
+                let sigma2 = vacuum_wavelength.to(Micrometer).value().powi(-2);
+
+                1.0 + 8.34254e-5 + 2.406147e-2 / (130.0 - sigma2) + 1.5998e-4 / (38.9 - sigma2)
+            }
+        }
+    }
+}
+
+impl<U: DynUnit> Energy<U> {
+    /// Wavelength of the photon with this energy measured in the given medium.
+    pub fn to_wavelength(&self, medium: Medium) -> Wavelength<Au> {
+        let vacuum_wavelength = Wavelength(TAU * SPEED_OF_LIGHT_AU / self.to_au(), Au);
+
+        vacuum_wavelength / medium.refractive_index(vacuum_wavelength)
+    }
+
+    /// Frequency of the photon with this energy.
+    pub fn to_frequency(&self) -> Frequency<Au> {
+        Frequency(self.to_au() / TAU, Au)
+    }
+}
+
+impl Energy<Au> {
+    /// Energy of the photon with the given wavelength measured in the given medium.
+    pub fn from_wavelength<U: DynUnit>(wavelength: Wavelength<U>, medium: Medium) -> Self {
+        let mut vacuum_wavelength = wavelength.to(Au);
+        if medium != Medium::Vacuum {
+            // refractive index depends on vacuum wavelength, a few iterations converge to machine precision.
+            for _ in 0..4 {
+                vacuum_wavelength = wavelength.to(Au) * medium.refractive_index(vacuum_wavelength);
+            }
+        }
+
+        Energy(TAU * SPEED_OF_LIGHT_AU / vacuum_wavelength.value(), Au)
+    }
+
+    /// Energy of the photon with the given frequency.
+    pub fn from_frequency<U: DynUnit>(frequency: Frequency<U>) -> Self {
+        Energy(TAU * frequency.to_au(), Au)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::units::{
+        distance_units::Nanometer,
+        energy_units::{CmInv, Energy, MHz, EV},
+        frequency_units::{Frequency, MegaHertz},
+    };
+
+    use super::{Medium, Wavelength};
+
+    #[test]
+    fn wavelength() {
+        let energy = Energy(1.0, EV);
+        let wavelength = energy.to_wavelength(Medium::Vacuum).to(Nanometer);
+        assert!((wavelength.value() - 1239.84198).abs() < 1e-5);
+
+        let energy = Energy(1e7 / 589.158, CmInv);
+        let air_wavelength = energy.to_wavelength(Medium::Air).to(Nanometer);
+        assert!((air_wavelength.value() - 588.995).abs() < 1e-3);
+
+        let back = Energy::from_wavelength(air_wavelength, Medium::Air);
+        assert!(back.approx_eq_rel(energy, 1e-12));
+
+        let wavelength = Wavelength(780.241, Nanometer);
+        let energy = Energy::from_wavelength(wavelength, Medium::Vacuum);
+        let back = energy.to_wavelength(Medium::Vacuum);
+        assert!(back.approx_eq_rel(wavelength, 1e-12));
+    }
+
+    #[test]
+    fn frequency() {
+        let energy = Energy(1.0, MHz);
+        let frequency = energy.to_frequency().to(MegaHertz);
+        assert!((frequency.value() - 1.0).abs() < 1e-12);
+
+        let energy = Energy::from_frequency(Frequency(3.0, MegaHertz));
+        assert!(energy.approx_eq_rel(Energy(3.0, MHz), 1e-12));
+        assert!(energy
+            .to_frequency()
+            .approx_eq_rel(Frequency(3.0, MegaHertz), 1e-12));
+    }
+}
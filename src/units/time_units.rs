@@ -0,0 +1,87 @@
+use std::f64::consts::TAU;
+
+use super::{
+    constants::CODATA,
+    dimensions::{TimeDim, UnitOf},
+    quantity::Quantity,
+    Au, Unit,
+};
+
+/// Atomic unit of time ħ / E_h in s.
+pub(crate) const AU_TIME: f64 = CODATA.planck / TAU / CODATA.hartree;
+
+/// Quantity representing time values, alias of [`Quantity`] with time dimension.
+/// # Examples
+/// ```
+/// use quantum::units::time_units::{Time, NanoSecond, PicoSecond};
+/// let time = Time(1.0, NanoSecond);
+/// assert!(time.to(PicoSecond).approx_eq_rel(Time(1000.0, PicoSecond), 1e-12));
+/// ```
+pub type Time<U, T = f64> = Quantity<TimeDim, U, T>;
+
+/// Creates time value in given unit.
+#[allow(non_snake_case)]
+pub fn Time<U: UnitOf<TimeDim>, T>(value: T, unit: U) -> Time<U, T> {
+    Quantity::new(value, unit)
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Second;
+
+impl Unit for Second {
+    const TO_AU_MUL: f64 = 1.0 / AU_TIME;
+    const SYMBOL: &'static str = "s";
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct MilliSecond;
+
+impl Unit for MilliSecond {
+    const TO_AU_MUL: f64 = 1e-3 * Second::TO_AU_MUL;
+    const SYMBOL: &'static str = "ms";
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct MicroSecond;
+
+impl Unit for MicroSecond {
+    const TO_AU_MUL: f64 = 1e-6 * Second::TO_AU_MUL;
+    const SYMBOL: &'static str = "μs";
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct NanoSecond;
+
+impl Unit for NanoSecond {
+    const TO_AU_MUL: f64 = 1e-9 * Second::TO_AU_MUL;
+    const SYMBOL: &'static str = "ns";
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct PicoSecond;
+
+impl Unit for PicoSecond {
+    const TO_AU_MUL: f64 = 1e-12 * Second::TO_AU_MUL;
+    const SYMBOL: &'static str = "ps";
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct FemtoSecond;
+
+impl Unit for FemtoSecond {
+    const TO_AU_MUL: f64 = 1e-15 * Second::TO_AU_MUL;
+    const SYMBOL: &'static str = "fs";
+}
+
+any_unit!(
+    /// Time unit chosen at runtime.
+    AnyTimeUnit: TimeDim {
+        Au => ["au", "a.u."],
+        Second => ["s"],
+        MilliSecond => ["ms"],
+        MicroSecond => ["us", "μs", "µs"],
+        NanoSecond => ["ns"],
+        PicoSecond => ["ps"],
+        FemtoSecond => ["fs"],
+    }
+);
@@ -0,0 +1,158 @@
+use std::{
+    fmt::Display,
+    iter::Sum,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// Value with its standard uncertainty.
+///
+/// Errors are propagated linearly assuming that the operands are uncorrelated,
+/// so that e.g. `x - x` has a nonzero error.
+/// # Examples
+/// ```
+/// use quantum::units::{Au, mass_units::{Dalton, Mass}, uncertain::Uncertain};
+/// let mass = Mass(Uncertain::new(86.909180, 0.000006), Dalton);
+/// let mass_au = mass.to(Au);
+/// assert!((mass_au.value().error() - 0.000006 * Mass(1.0, Dalton).to_au()).abs() < 1e-9);
+///
+/// let sum = Uncertain::new(1.0, 0.3) + Uncertain::new(2.0, 0.4);
+/// assert_eq!(sum, Uncertain::new(3.0, 0.5));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Uncertain {
+    value: f64,
+    error: f64,
+}
+
+impl Uncertain {
+    pub fn new(value: f64, error: f64) -> Self {
+        Self {
+            value,
+            error: error.abs(),
+        }
+    }
+
+    /// Value without uncertainty.
+    pub fn exact(value: f64) -> Self {
+        Self::new(value, 0.0)
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn error(&self) -> f64 {
+        self.error
+    }
+
+    pub fn relative_error(&self) -> f64 {
+        self.error / self.value.abs()
+    }
+
+    pub fn recip(&self) -> Self {
+        Self::new(1.0 / self.value, self.error / (self.value * self.value))
+    }
+
+    pub fn powi(&self, n: i32) -> Self {
+        Self::new(
+            self.value.powi(n),
+            (n as f64 * self.value.powi(n - 1)) * self.error,
+        )
+    }
+
+    pub fn sqrt(&self) -> Self {
+        let value = self.value.sqrt();
+
+        Self::new(value, self.error / (2.0 * value))
+    }
+}
+
+impl From<f64> for Uncertain {
+    fn from(value: f64) -> Self {
+        Self::exact(value)
+    }
+}
+
+impl Display for Uncertain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(
+                f,
+                "{:.*} ± {:.*}",
+                precision, self.value, precision, self.error
+            ),
+            None => write!(f, "{} ± {}", self.value, self.error),
+        }
+    }
+}
+
+impl Add for Uncertain {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.value + rhs.value, self.error.hypot(rhs.error))
+    }
+}
+
+impl Sub for Uncertain {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.value - rhs.value, self.error.hypot(rhs.error))
+    }
+}
+
+impl Mul for Uncertain {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.value * rhs.value,
+            (self.error * rhs.value).hypot(self.value * rhs.error),
+        )
+    }
+}
+
+impl Div for Uncertain {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let value = self.value / rhs.value;
+
+        Self::new(
+            value,
+            (self.error / rhs.value).hypot(value * rhs.error / rhs.value),
+        )
+    }
+}
+
+impl Neg for Uncertain {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.value, self.error)
+    }
+}
+
+impl Mul<f64> for Uncertain {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(self.value * rhs, self.error * rhs)
+    }
+}
+
+impl Div<f64> for Uncertain {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Self::new(self.value / rhs, self.error / rhs)
+    }
+}
+
+impl Sum for Uncertain {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, x| acc + x)
+    }
+}
@@ -1,6 +1,6 @@
 use std::f64::consts::FRAC_PI_2;
 
-use crate::units::{energy_units::Energy, Au, Unit};
+use crate::units::{dimensions::UnitOf, quantity::Quantity, Au};
 
 pub fn asymptotic_bessel_j(x: f64, l: usize) -> f64 {
     (x - FRAC_PI_2 * (l as f64)).sin()
@@ -33,12 +33,44 @@ pub fn linspace(start: f64, end: f64, n: usize) -> Vec<f64> {
     result
 }
 
-pub fn unit_linspace<U: Unit>(start: Energy<U>, end: Energy<U>, n: usize) -> Vec<Energy<U>> {
+/// Logarithmically spaced values between `start` and `end` inclusive, both have to be of the same sign.
+/// # Panics
+/// Panics if `start` or `end` is zero or they are of opposite sign.
+pub fn logspace(start: f64, end: f64, n: usize) -> Vec<f64> {
+    assert!(
+        start * end > 0.0,
+        "logspace endpoints have to be nonzero and of the same sign"
+    );
+
+    linspace(start.abs().ln(), end.abs().ln(), n)
+        .into_iter()
+        .map(|x| start.signum() * x.exp())
+        .collect()
+}
+
+/// Geometric sequence of `n` values starting at `start` with consecutive values differing by `ratio`.
+pub fn geomspace(start: f64, ratio: f64, n: usize) -> Vec<f64> {
+    let mut result = Vec::with_capacity(n);
+    let mut value = start;
+
+    for _ in 0..n {
+        result.push(value);
+        value *= ratio;
+    }
+
+    result
+}
+
+pub fn unit_linspace<D, U: UnitOf<D>>(
+    start: Quantity<D, U>,
+    end: Quantity<D, U>,
+    n: usize,
+) -> Vec<Quantity<D, U>> {
     let start_au = start.to_au();
     let end_au = end.to_au();
 
     linspace(start_au, end_au, n)
         .into_iter()
-        .map(|x| Energy(x, Au).to(start.unit()))
+        .map(|x| Quantity::new(x, Au).to(start.unit()))
         .collect()
 }